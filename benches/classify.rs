@@ -0,0 +1,35 @@
+/*#![feature(test)]
+extern crate test;
+
+extern crate nmea;
+
+use nmea::{classify, parse, SentenceType};
+use test::Bencher;
+
+const INPUT: [&[u8]; 4] = [
+    b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76",
+    b"$GPRMC,092750.000,A,5321.6802,N,00630.3372,W,0.02,31.66,280511,,,A*43",
+    b"$GPGSV,3,1,12,01,49,196,41,03,71,278,32,06,02,323,27,11,21,196,39*72",
+    b"$GPGSA,A,3,23,31,22,16,03,07,,,,,,,1.8,1.1,1.4*3E",
+];
+
+#[bench]
+fn bench_classify(b: &mut Bencher) {
+    b.iter(|| {
+        INPUT
+            .iter()
+            .filter(|line| matches!(classify(line), Some((_, SentenceType::GGA))))
+            .count()
+    });
+}
+
+#[bench]
+fn bench_parse(b: &mut Bencher) {
+    b.iter(|| {
+        INPUT
+            .iter()
+            .filter(|line| parse(line).unwrap().sentence_type() == SentenceType::GGA)
+            .count()
+    });
+}
+*/
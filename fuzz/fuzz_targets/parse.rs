@@ -0,0 +1,17 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = nmea::parse(data);
+    let _ = nmea::parse_with_options(
+        data,
+        &nmea::ParseOptions {
+            lenient: true,
+            skip_invalid_fix: false,
+            decimal_comma: true,
+            max_gsa_prns: nmea::MAX_GSA_PRNS,
+        },
+    );
+    let mut state = nmea::Nmea::new();
+    let _ = state.update(data);
+});
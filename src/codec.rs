@@ -0,0 +1,227 @@
+//! Framing of NMEA byte stream for `tokio_util::codec::FramedRead`
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::io;
+
+use bytes::{Buf, BytesMut};
+use futures_core::Stream;
+use tokio::io::AsyncRead;
+use tokio_util::codec::{Decoder, FramedRead};
+
+use crate::parse::MAX_LINE_LEN;
+use crate::{parse_with_options, NmeaError, ParseOptions, ParseResult};
+
+/// Decoder of NMEA sentences separated by `\n` or `\r\n`.
+///
+/// Item of decoder is result of `parse` of one line, so malformed
+/// sentence doesn't stop `FramedRead`, only I/O errors do. Bytes
+/// before `$` or `!` (like remainder of sentence cut on receiver
+/// start) and empty lines are skipped.
+#[derive(Debug, Default)]
+pub struct NmeaCodec {
+    options: ParseOptions,
+}
+
+impl NmeaCodec {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decoder parsing sentences with `parse_with_options`
+    pub fn with_options(options: ParseOptions) -> Self {
+        NmeaCodec { options }
+    }
+
+    fn parse_line(&self, line: &[u8]) -> Option<Result<ParseResult, NmeaError>> {
+        let start = line.iter().position(|&c| c == b'$' || c == b'!')?;
+        let mut line = &line[start..];
+        while let Some((&last, rest)) = line.split_last() {
+            if last != b'\r' && last != b'\n' {
+                break;
+            }
+            line = rest;
+        }
+        Some(parse_with_options(line, &self.options))
+    }
+}
+
+impl Decoder for NmeaCodec {
+    type Item = Result<ParseResult, NmeaError>;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, io::Error> {
+        while let Some(end) = src.iter().position(|&c| c == b'\n') {
+            let line = src.split_to(end + 1);
+            if line.len() > MAX_LINE_LEN {
+                return Ok(Some(Err(NmeaError::TooLong)));
+            }
+            if let Some(result) = self.parse_line(&line) {
+                return Ok(Some(result));
+            }
+        }
+        if src.len() > MAX_LINE_LEN {
+            src.clear();
+            return Ok(Some(Err(NmeaError::TooLong)));
+        }
+        Ok(None)
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, io::Error> {
+        if let Some(item) = self.decode(src)? {
+            return Ok(Some(item));
+        }
+        // last line without terminator
+        let result = self.parse_line(src);
+        src.advance(src.len());
+        Ok(result)
+    }
+}
+
+/// Stream of parsed sentences from `AsyncRead`, like serial port:
+///
+/// ```no_run
+/// # async fn read(port: impl tokio::io::AsyncRead + Unpin) {
+/// use futures_util::StreamExt;
+/// use nmea::NmeaStream;
+///
+/// let mut stream = NmeaStream::new(port);
+/// while let Some(sentence) = stream.next().await {
+///     println!("{:?}", sentence);
+/// }
+/// # }
+/// ```
+///
+/// I/O errors are reported as `NmeaError::Io`, after them
+/// stream ends.
+#[derive(Debug)]
+pub struct NmeaStream<R> {
+    inner: FramedRead<R, NmeaCodec>,
+}
+
+impl<R: AsyncRead> NmeaStream<R> {
+    pub fn new(reader: R) -> Self {
+        Self::with_codec(reader, NmeaCodec::new())
+    }
+
+    pub fn with_codec(reader: R, codec: NmeaCodec) -> Self {
+        NmeaStream {
+            inner: FramedRead::new(reader, codec),
+        }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner.into_inner()
+    }
+}
+
+impl<R: AsyncRead + Unpin> Stream for NmeaStream<R> {
+    type Item = Result<ParseResult, NmeaError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.get_mut().inner)
+            .poll_next(cx)
+            .map(|item| {
+                item.map(|result| result.unwrap_or_else(|err| Err(NmeaError::Io(err.kind()))))
+            })
+    }
+}
+
+#[cfg(all(test, feature = "all-sentences"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_chunks() {
+        let input: &[u8] = b"0.0,M*25\r\n\
+            $GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76\r\n\
+            \r\n\
+            $HCHDM,238.5,M*26\r\n\
+            $GPRMC,092750.000,A,5321.6802,N,00630.3372,W,0.02,31.66,280511,,,A*43\n\
+            $HCHDM,238.5,M*25";
+        for chunk_len in 1..16 {
+            let mut codec = NmeaCodec::new();
+            let mut buf = BytesMut::new();
+            let mut results = Vec::new();
+            for chunk in input.chunks(chunk_len) {
+                buf.extend_from_slice(chunk);
+                while let Some(result) = codec.decode(&mut buf).unwrap() {
+                    results.push(result);
+                }
+            }
+            // last line is complete only at the end of stream
+            assert_eq!(results.len(), 3);
+            while let Some(result) = codec.decode_eof(&mut buf).unwrap() {
+                results.push(result);
+            }
+            assert!(buf.is_empty());
+
+            assert_eq!(results.len(), 4);
+            assert!(matches!(results[0], Ok(ParseResult::GGA(_))));
+            assert!(matches!(
+                results[1],
+                Err(NmeaError::ChecksumMismatch { .. })
+            ));
+            assert!(matches!(results[2], Ok(ParseResult::RMC(_))));
+            assert!(matches!(results[3], Ok(ParseResult::HDM(_))));
+        }
+    }
+
+    #[test]
+    fn test_decode_partial_and_too_long() {
+        let mut codec = NmeaCodec::new();
+        let mut buf = BytesMut::from(&b"$GPGGA,092750.000,5321.68"[..]);
+        assert!(codec.decode(&mut buf).unwrap().is_none());
+        assert_eq!(buf.len(), 25);
+
+        let mut buf = BytesMut::from(&[b'1'; MAX_LINE_LEN + 1][..]);
+        assert!(matches!(
+            codec.decode(&mut buf).unwrap(),
+            Some(Err(NmeaError::TooLong))
+        ));
+        assert!(buf.is_empty());
+        assert!(codec.decode_eof(&mut buf).unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_stream() {
+        use futures_util::StreamExt;
+
+        let input: &[u8] =
+            b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76\r\n\
+            $HCHDM,238.5,M*26\r\n\
+            $HCHDM,238.5,M*25\r\n";
+        let results: Vec<_> = NmeaStream::new(input).collect().await;
+        assert_eq!(results.len(), 3);
+        assert!(matches!(results[0], Ok(ParseResult::GGA(_))));
+        assert!(matches!(
+            results[1],
+            Err(NmeaError::ChecksumMismatch { .. })
+        ));
+        assert!(matches!(results[2], Ok(ParseResult::HDM(_))));
+    }
+
+    struct FailingReader;
+
+    impl AsyncRead for FailingReader {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            _buf: &mut tokio::io::ReadBuf<'_>,
+        ) -> Poll<io::Result<()>> {
+            Poll::Ready(Err(io::ErrorKind::BrokenPipe.into()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stream_io_error() {
+        use futures_util::StreamExt;
+
+        let mut stream = NmeaStream::new(FailingReader);
+        assert_eq!(
+            stream.next().await.unwrap().err(),
+            Some(NmeaError::Io(io::ErrorKind::BrokenPipe))
+        );
+        assert!(stream.next().await.is_none());
+    }
+}
@@ -0,0 +1,103 @@
+/// Latitude or longitude in decimal degrees, negative for south/west,
+/// with conversions to degrees-minutes(-seconds) forms
+///
+/// # Examples
+///
+/// ```
+/// use nmea::Coordinate;
+///
+/// let lat = Coordinate::from(-33.5);
+/// assert_eq!(lat.to_ddm(), (33, 30.));
+/// assert_eq!(lat.to_dms(), (33, 30, 0.));
+/// assert_eq!(lat.latitude_hemisphere(), 'S');
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Coordinate(pub f64);
+
+impl Coordinate {
+    /// Unsigned degrees, minutes and seconds, sign is given by
+    /// `latitude_hemisphere` or `longitude_hemisphere`
+    pub fn to_dms(&self) -> (u32, u32, f64) {
+        let (deg, min) = self.to_ddm();
+        let whole_min = min as u32;
+        (deg, whole_min, (min - f64::from(whole_min)) * 60.)
+    }
+
+    /// Unsigned degrees and decimal minutes, same form as in NMEA
+    /// sentences, where sign is given by hemisphere field
+    pub fn to_ddm(&self) -> (u32, f64) {
+        let abs = if self.0 < 0. { -self.0 } else { self.0 };
+        let deg = abs as u32;
+        (deg, (abs - f64::from(deg)) * 60.)
+    }
+
+    /// `N` or `S` if coordinate is latitude
+    pub fn latitude_hemisphere(&self) -> char {
+        if self.0 < 0. {
+            'S'
+        } else {
+            'N'
+        }
+    }
+
+    /// `E` or `W` if coordinate is longitude
+    pub fn longitude_hemisphere(&self) -> char {
+        if self.0 < 0. {
+            'W'
+        } else {
+            'E'
+        }
+    }
+}
+
+impl From<f64> for Coordinate {
+    fn from(degrees: f64) -> Self {
+        Coordinate(degrees)
+    }
+}
+
+impl From<Coordinate> for f64 {
+    fn from(coord: Coordinate) -> Self {
+        coord.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coordinate_dms() {
+        let lat = Coordinate::from(53. + 21. / 60. + 40.812 / 3600.);
+        let (deg, min, sec) = lat.to_dms();
+        assert_eq!((deg, min), (53, 21));
+        assert!((sec - 40.812).abs() < 1e-6);
+        assert_eq!(lat.latitude_hemisphere(), 'N');
+
+        let lon = Coordinate::from(-74.006);
+        let (deg, min, sec) = lon.to_dms();
+        assert_eq!((deg, min), (74, 0));
+        assert!((sec - 21.6).abs() < 1e-6);
+        assert_eq!(lon.longitude_hemisphere(), 'W');
+        let (deg, min) = lon.to_ddm();
+        assert_eq!(deg, 74);
+        assert!((min - 0.36).abs() < 1e-9);
+
+        assert_eq!(Coordinate::default().to_dms(), (0, 0, 0.));
+        assert_eq!(Coordinate::from(0.).longitude_hemisphere(), 'E');
+        let back: f64 = lon.into();
+        assert_eq!(back, -74.006);
+
+        // sign of coordinates between -1 and 0 is kept by hemisphere only
+        let lat = Coordinate::from(-0.5);
+        assert_eq!(lat.to_ddm(), (0, 30.));
+        assert_eq!(lat.to_dms(), (0, 30, 0.));
+        assert_eq!(lat.latitude_hemisphere(), 'S');
+        let lon = Coordinate::from(-0.1278);
+        let (deg, min, sec) = lon.to_dms();
+        assert_eq!((deg, min), (0, 7));
+        assert!((sec - 40.08).abs() < 1e-6);
+        assert_eq!(lon.longitude_hemisphere(), 'W');
+    }
+}
@@ -0,0 +1,141 @@
+//! CSV export of fixes, for spreadsheets and quick analysis
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Display, Write};
+
+use crate::export::write_string;
+use crate::{Observation, ParseResult};
+
+const HEADER: &str = "time,lat,lon,alt,sog,cog,fix_type,satellites,hdop\n";
+
+/// CSV table with header and one row per fix reported by GGA and RMC
+/// sentences, other results are skipped. GGA and RMC of the same fix time
+/// following each other are combined into one row, fields not reported
+/// by any of them are empty cells.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "rmc")] {
+/// use nmea::{fixes_to_csv, parse};
+///
+/// let rmc = parse(b"$GPRMC,092750.000,A,5321.6802,N,00630.3372,W,0.02,31.66,280511,,,A*43")
+///     .unwrap();
+/// assert_eq!(
+///     fixes_to_csv(&[rmc]),
+///     "time,lat,lon,alt,sog,cog,fix_type,satellites,hdop\n\
+///      09:27:50,53.361336666666666,-6.50562,,0.02,31.66,,,\n"
+/// );
+/// # }
+/// ```
+pub fn fixes_to_csv(results: &[ParseResult]) -> String {
+    let mut rows: Vec<Observation> = Vec::new();
+    for result in results {
+        let obs = match fix_row(result) {
+            Some(obs) => obs,
+            None => continue,
+        };
+        match rows.last_mut() {
+            Some(prev) if obs.fix_time.is_some() && prev.fix_time == obs.fix_time => {
+                merge(prev, obs)
+            }
+            _ => rows.push(obs),
+        }
+    }
+    write_string(|out| {
+        out.push_str(HEADER);
+        rows.iter().try_for_each(|row| write_row(out, row))
+    })
+}
+
+fn fix_row(result: &ParseResult) -> Option<Observation> {
+    match result {
+        #[cfg(feature = "gga")]
+        ParseResult::GGA(gga) => Some(Observation {
+            fix_time: gga.fix_time,
+            fix_type: gga.fix_type.clone(),
+            latitude: gga.latitude,
+            longitude: gga.longitude,
+            altitude: gga.altitude,
+            fix_satellites: gga.fix_satellites,
+            hdop: gga.hdop,
+            ..Observation::default()
+        }),
+        #[cfg(feature = "rmc")]
+        ParseResult::RMC(rmc) => Some(Observation {
+            fix_time: rmc.fix_time,
+            latitude: rmc.lat,
+            longitude: rmc.lon,
+            speed_over_ground: rmc.speed_over_ground,
+            true_course: rmc.true_course,
+            ..Observation::default()
+        }),
+        _ => None,
+    }
+}
+
+fn merge(row: &mut Observation, obs: Observation) {
+    row.fix_type = row.fix_type.take().or(obs.fix_type);
+    row.latitude = row.latitude.or(obs.latitude);
+    row.longitude = row.longitude.or(obs.longitude);
+    row.altitude = row.altitude.or(obs.altitude);
+    row.speed_over_ground = row.speed_over_ground.or(obs.speed_over_ground);
+    row.true_course = row.true_course.or(obs.true_course);
+    row.fix_satellites = row.fix_satellites.or(obs.fix_satellites);
+    row.hdop = row.hdop.or(obs.hdop);
+}
+
+fn write_row<W: Write>(w: &mut W, row: &Observation) -> fmt::Result {
+    cell(w, row.fix_time)?;
+    w.write_char(',')?;
+    cell(w, row.latitude)?;
+    w.write_char(',')?;
+    cell(w, row.longitude)?;
+    w.write_char(',')?;
+    cell(w, row.altitude)?;
+    w.write_char(',')?;
+    cell(w, row.speed_over_ground)?;
+    w.write_char(',')?;
+    cell(w, row.true_course)?;
+    w.write_char(',')?;
+    if let Some(fix_type) = &row.fix_type {
+        write!(w, "{:?}", fix_type)?;
+    }
+    w.write_char(',')?;
+    cell(w, row.fix_satellites)?;
+    w.write_char(',')?;
+    cell(w, row.hdop)?;
+    w.write_char('\n')
+}
+
+fn cell<W: Write, T: Display>(w: &mut W, v: Option<T>) -> fmt::Result {
+    match v {
+        Some(v) => write!(w, "{}", v),
+        None => Ok(()),
+    }
+}
+
+#[cfg(all(test, feature = "gga", feature = "rmc"))]
+mod tests {
+    use super::*;
+    use crate::export::parsed_track;
+
+    #[test]
+    fn test_fixes_to_csv() {
+        let csv = fixes_to_csv(&parsed_track());
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "time,lat,lon,alt,sog,cog,fix_type,satellites,hdop",
+                "09:27:50,53.361336666666666,-6.50562,61.7,0.02,31.66,Gps,8,1.03",
+                ",,,,,,Invalid,,",
+                "09:27:51,53.361336666666666,-6.5056183333333335,,,,Gps,8,1.03",
+                "09:27:52,53.361336666666666,-6.505616666666667,,0.06,31.66,,,",
+            ]
+        );
+        assert!(lines.iter().all(|line| line.matches(',').count() == 8));
+
+        assert_eq!(fixes_to_csv(&[]), HEADER);
+    }
+}
@@ -0,0 +1,112 @@
+//! Detection of sentences repeated for the same epoch
+
+use alloc::vec::Vec;
+
+use chrono::NaiveTime;
+
+use crate::{ParseResult, SentenceType};
+
+/// Detects sentences which arrive twice for the same epoch, for example
+/// when the link goes through a splitter.
+///
+/// Filter remembers fix time of the last sentence of each type, sentence
+/// with the same fix time as the previous one of its type is duplicate.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "gga")] {
+/// use nmea::{parse, DedupFilter};
+///
+/// let gga = parse(b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76")
+///     .unwrap();
+/// let mut filter = DedupFilter::new();
+/// assert!(!filter.is_duplicate(&gga));
+/// assert!(filter.is_duplicate(&gga));
+/// # }
+/// ```
+#[derive(Default, Debug, Clone)]
+pub struct DedupFilter {
+    last: Vec<(SentenceType, NaiveTime)>,
+}
+
+impl DedupFilter {
+    pub fn new() -> DedupFilter {
+        DedupFilter::default()
+    }
+
+    /// Check sentence and remember its fix time, so every sentence
+    /// should be checked once. Sentences without fix time
+    /// (GGA with empty time field, VTG, GSV, ...) are never duplicates.
+    pub fn is_duplicate(&mut self, result: &ParseResult) -> bool {
+        let time = match fix_time(result) {
+            Some(time) => time,
+            None => return false,
+        };
+        let sentence = result.sentence_type();
+        match self.last.iter_mut().find(|(t, _)| *t == sentence) {
+            Some((_, last)) => {
+                let duplicate = *last == time;
+                *last = time;
+                duplicate
+            }
+            None => {
+                self.last.push((sentence, time));
+                false
+            }
+        }
+    }
+}
+
+fn fix_time(result: &ParseResult) -> Option<NaiveTime> {
+    match result {
+        #[cfg(feature = "gga")]
+        ParseResult::GGA(gga) => gga.fix_time,
+        #[cfg(feature = "rmc")]
+        ParseResult::RMC(rmc) => rmc.fix_time,
+        #[cfg(feature = "gll")]
+        ParseResult::GLL(gll) => Some(gll.fix_time),
+        #[cfg(feature = "gns")]
+        ParseResult::GNS(gns) => gns.fix_time,
+        #[cfg(feature = "gst")]
+        ParseResult::GST(gst) => gst.fix_time,
+        #[cfg(feature = "ggk")]
+        ParseResult::GGK(ggk) => ggk.fix_time,
+        _ => None,
+    }
+}
+
+#[cfg(all(test, feature = "all-sentences"))]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn test_dedup_filter() {
+        let mut filter = DedupFilter::new();
+        let gga = parse(b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76")
+            .unwrap();
+        assert!(!filter.is_duplicate(&gga));
+        assert!(filter.is_duplicate(&gga));
+        assert!(filter.is_duplicate(&gga));
+
+        // other sentence type of the same epoch
+        let rmc = parse(b"$GPRMC,092750.000,A,5321.6802,N,00630.3372,W,0.02,31.66,280511,,,A*43")
+            .unwrap();
+        assert!(!filter.is_duplicate(&rmc));
+        assert!(filter.is_duplicate(&rmc));
+
+        let next = parse(b"$GPGGA,092751.000,5321.6802,N,00630.3371,W,1,8,1.03,61.7,M,55.3,M,,*75")
+            .unwrap();
+        assert!(!filter.is_duplicate(&next));
+        assert!(!filter.is_duplicate(&gga));
+
+        // without time
+        let no_time = parse(b"$GPGGA,,,,,,0,,,,M,,M,,*66").unwrap();
+        assert!(!filter.is_duplicate(&no_time));
+        assert!(!filter.is_duplicate(&no_time));
+        let vtg = parse(b"$GPVTG,054.7,T,034.4,M,005.5,N,010.2,K*48").unwrap();
+        assert!(!filter.is_duplicate(&vtg));
+        assert!(!filter.is_duplicate(&vtg));
+    }
+}
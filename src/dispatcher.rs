@@ -0,0 +1,207 @@
+//! Callbacks per sentence type for bytes fed in arbitrary chunks
+
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::parse::MAX_LINE_LEN;
+#[cfg(feature = "gga")]
+use crate::GgaData;
+#[cfg(feature = "gll")]
+use crate::GllData;
+#[cfg(feature = "gsa")]
+use crate::GsaData;
+#[cfg(feature = "gsv")]
+use crate::GsvData;
+#[cfg(feature = "rmc")]
+use crate::RmcData;
+#[cfg(feature = "vtg")]
+use crate::VtgData;
+use crate::{parse_with_options, NmeaError, ParseOptions, ParseResult, SentenceType};
+
+type Handler<'a> = Box<dyn FnMut(&ParseResult) + 'a>;
+
+/// Parser of byte stream invoking handlers registered for sentence types.
+///
+/// Bytes may come in chunks of any size, like reads from serial port,
+/// sentences are parsed when their line terminator arrives.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "gga")] {
+/// use nmea::NmeaDispatcher;
+///
+/// let mut fixes = 0;
+/// let mut dispatcher = NmeaDispatcher::new();
+/// dispatcher.on_gga(|gga| {
+///     assert_eq!(gga.fix_satellites, Some(8));
+///     fixes += 1;
+/// });
+/// dispatcher.feed(b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,");
+/// dispatcher.feed(b"1,8,1.03,61.7,M,55.2,M,,*76\r\n");
+/// drop(dispatcher);
+/// assert_eq!(fixes, 1);
+/// # }
+/// ```
+#[derive(Default)]
+pub struct NmeaDispatcher<'a> {
+    options: ParseOptions,
+    buf: Vec<u8>,
+    handlers: Vec<(SentenceType, Handler<'a>)>,
+    error_handler: Option<Box<dyn FnMut(NmeaError) + 'a>>,
+}
+
+impl<'a> NmeaDispatcher<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Dispatcher parsing sentences with `parse_with_options`
+    pub fn with_options(options: ParseOptions) -> Self {
+        NmeaDispatcher {
+            options,
+            ..Self::default()
+        }
+    }
+
+    /// Register handler for all sentences of `sentence_type`, including
+    /// unsupported ones, several handlers of one type are called in order
+    /// of registration
+    pub fn on<F: FnMut(&ParseResult) + 'a>(&mut self, sentence_type: SentenceType, handler: F) {
+        self.handlers.push((sentence_type, Box::new(handler)));
+    }
+
+    /// Register handler for lines which fail to parse
+    pub fn on_error<F: FnMut(NmeaError) + 'a>(&mut self, handler: F) {
+        self.error_handler = Some(Box::new(handler));
+    }
+
+    /// Append bytes to buffer and dispatch all complete sentences in it
+    pub fn feed(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+        let mut start = 0;
+        while let Some(len) = self.buf[start..].iter().position(|&c| c == b'\n') {
+            let line = &self.buf[start..start + len];
+            let line = line.strip_suffix(b"\r").unwrap_or(line);
+            start += len + 1;
+            if line.is_empty() {
+                continue;
+            }
+            let result = if line.len() > MAX_LINE_LEN {
+                Err(NmeaError::TooLong)
+            } else {
+                parse_with_options(line, &self.options)
+            };
+            match result {
+                Ok(result) => {
+                    let sentence_type = result.sentence_type();
+                    for (_, handler) in self
+                        .handlers
+                        .iter_mut()
+                        .filter(|(ty, _)| *ty == sentence_type)
+                    {
+                        handler(&result);
+                    }
+                }
+                Err(err) => {
+                    if let Some(handler) = &mut self.error_handler {
+                        handler(err);
+                    }
+                }
+            }
+        }
+        self.buf.drain(..start);
+        if self.buf.len() > MAX_LINE_LEN {
+            self.buf.clear();
+            if let Some(handler) = &mut self.error_handler {
+                handler(NmeaError::TooLong);
+            }
+        }
+    }
+}
+
+macro_rules! impl_typed_handler {
+    ($(#[$attr:meta] $name:ident, $variant:ident, $data:ty;)*) => {
+        impl<'a> NmeaDispatcher<'a> {
+            $(
+                #[$attr]
+                #[doc = concat!("Register handler for ", stringify!($variant), " sentences")]
+                pub fn $name<F: FnMut(&$data) + 'a>(&mut self, mut handler: F) {
+                    self.on(SentenceType::$variant, move |result| {
+                        if let ParseResult::$variant(data) = result {
+                            handler(data);
+                        }
+                    });
+                }
+            )*
+        }
+    };
+}
+
+impl_typed_handler!(
+    #[cfg(feature = "gga")] on_gga, GGA, GgaData;
+    #[cfg(feature = "rmc")] on_rmc, RMC, RmcData;
+    #[cfg(feature = "gsa")] on_gsa, GSA, GsaData;
+    #[cfg(feature = "gsv")] on_gsv, GSV, GsvData;
+    #[cfg(feature = "vtg")] on_vtg, VTG, VtgData;
+    #[cfg(feature = "gll")] on_gll, GLL, GllData;
+);
+
+#[cfg(all(test, feature = "all-sentences"))]
+mod tests {
+    use super::*;
+    use core::cell::{Cell, RefCell};
+
+    #[test]
+    fn test_dispatch() {
+        let input: &[u8] =
+            b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76\r\n\
+            $HCHDM,238.5,M*26\r\n\
+            \r\n\
+            $GPRMC,092750.000,A,5321.6802,N,00630.3372,W,0.02,31.66,280511,,,A*43\n\
+            $GPAAM,A,A,0.10,N,WPTNME*32\r\n\
+            $GPGGA,,,,,,0,,,,M,,M,,*66\r\n\
+            $HCHDM,238.5,M*25";
+        for chunk_len in 1..16 {
+            let fixes = RefCell::new(Vec::new());
+            let rmc = Cell::new(0);
+            let aam = Cell::new(0);
+            let errors = Cell::new(0);
+            let mut dispatcher = NmeaDispatcher::new();
+            dispatcher.on_gga(|gga| fixes.borrow_mut().push(gga.fix_type.clone()));
+            dispatcher.on_rmc(|_| rmc.set(rmc.get() + 1));
+            dispatcher.on(SentenceType::AAM, |result| {
+                assert!(matches!(result, ParseResult::Unsupported { .. }));
+                aam.set(aam.get() + 1);
+            });
+            dispatcher.on_error(|err| {
+                assert!(matches!(err, NmeaError::ChecksumMismatch { .. }));
+                errors.set(errors.get() + 1);
+            });
+            for chunk in input.chunks(chunk_len) {
+                dispatcher.feed(chunk);
+            }
+            drop(dispatcher);
+            assert_eq!(
+                *fixes.borrow(),
+                [Some(crate::FixType::Gps), Some(crate::FixType::Invalid)]
+            );
+            assert_eq!(rmc.get(), 1);
+            assert_eq!(aam.get(), 1);
+            assert_eq!(errors.get(), 1);
+        }
+    }
+
+    #[test]
+    fn test_dispatch_too_long() {
+        let errors = Cell::new(0);
+        let mut dispatcher = NmeaDispatcher::new();
+        dispatcher.on_error(|err| {
+            assert_eq!(err, NmeaError::TooLong);
+            errors.set(errors.get() + 1);
+        });
+        dispatcher.feed(&[b'1'; MAX_LINE_LEN + 1]);
+        assert!(dispatcher.buf.is_empty());
+        drop(dispatcher);
+        assert_eq!(errors.get(), 1);
+    }
+}
@@ -0,0 +1,149 @@
+//! Great circle distance and bearing between positions, trigonometry
+//! of floats is not in `core`, so this requires `std`
+
+use crate::heading::normalize_degrees_f64;
+use crate::{GgaData, GllData, GnsData, RmcData};
+
+/// Mean radius of WGS84 ellipsoid, (2a + b) / 3
+pub const EARTH_MEAN_RADIUS_M: f64 = 6_371_008.8;
+
+/// Sentence data reporting position
+pub trait Position {
+    /// Latitude and longitude in degrees, `None` if unknown
+    fn position(&self) -> Option<(f64, f64)>;
+}
+
+impl Position for GgaData {
+    fn position(&self) -> Option<(f64, f64)> {
+        Some((self.latitude?, self.longitude?))
+    }
+}
+
+impl Position for RmcData {
+    fn position(&self) -> Option<(f64, f64)> {
+        Some((self.lat?, self.lon?))
+    }
+}
+
+impl Position for GllData {
+    fn position(&self) -> Option<(f64, f64)> {
+        Some((self.latitude, self.longitude))
+    }
+}
+
+impl Position for GnsData {
+    fn position(&self) -> Option<(f64, f64)> {
+        Some((self.latitude?, self.longitude?))
+    }
+}
+
+/// Great circle distance in meters between two points given in degrees.
+///
+/// Earth is treated as a sphere with `EARTH_MEAN_RADIUS_M` radius,
+/// so error compared to distance on ellipsoid is up to 0.5%,
+/// altitude is ignored.
+pub fn haversine_distance_m(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let (lat1, lat2) = (lat1.to_radians(), lat2.to_radians());
+    let d_lat = lat2 - lat1;
+    let d_lon = (lon2 - lon1).to_radians();
+    let a = (d_lat / 2.).sin().powi(2) + lat1.cos() * lat2.cos() * (d_lon / 2.).sin().powi(2);
+    2. * EARTH_MEAN_RADIUS_M * a.sqrt().min(1.).asin()
+}
+
+/// `haversine_distance_m` between positions of two fixes,
+/// `None` if any of them has no position
+pub fn fix_distance_m<A: Position, B: Position>(a: &A, b: &B) -> Option<f64> {
+    let (lat1, lon1) = a.position()?;
+    let (lat2, lon2) = b.position()?;
+    Some(haversine_distance_m(lat1, lon1, lat2, lon2))
+}
+
+/// Initial bearing (forward azimuth) in degrees `[0, 360)` clockwise from
+/// true north on great circle path from first point to second one.
+///
+/// Bearing changes along the path, so it is course to steer only
+/// at the start point.
+pub fn initial_bearing_deg(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+    let (lat1, lat2) = (lat1.to_radians(), lat2.to_radians());
+    let d_lon = (lon2 - lon1).to_radians();
+    let y = d_lon.sin() * lat2.cos();
+    let x = lat1.cos() * lat2.sin() - lat1.sin() * lat2.cos() * d_lon.cos();
+    normalize_degrees_f64(y.atan2(x).to_degrees())
+}
+
+/// `initial_bearing_deg` from position of first fix to second one,
+/// `None` if any of them has no position
+pub fn fix_bearing_deg<A: Position, B: Position>(from: &A, to: &B) -> Option<f64> {
+    let (lat1, lon1) = from.position()?;
+    let (lat2, lon2) = to.position()?;
+    Some(initial_bearing_deg(lat1, lon1, lat2, lon2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_haversine_distance() {
+        // Paris - London, 343.5 km by great circle
+        let d = haversine_distance_m(48.8566, 2.3522, 51.5074, -0.1278);
+        assert!((d - 343_500.).abs() < 343_500. * 0.005, "distance {}", d);
+        assert_eq!(haversine_distance_m(53.36, -6.5, 53.36, -6.5), 0.);
+        // antipodes
+        let d = haversine_distance_m(0., 0., 0., 180.);
+        assert!((d - std::f64::consts::PI * EARTH_MEAN_RADIUS_M).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_initial_bearing() {
+        for &(lat2, lon2, expected) in &[
+            (1., 0., 0.),
+            (0., 1., 90.),
+            (-1., 0., 180.),
+            (0., -1., 270.),
+            (1., 1., 45.),
+        ] {
+            let b = initial_bearing_deg(0., 0., lat2, lon2);
+            assert!((b - expected).abs() < 0.01, "bearing {} to {}", b, expected);
+        }
+        // just west of north wraps to the top of range, not below 0
+        let b = initial_bearing_deg(0., 0., 1., -1e-6);
+        assert!(b > 359.99 && b < 360., "bearing {}", b);
+        let b = initial_bearing_deg(0., 0., 1., -1e-300);
+        assert!((0. ..360.).contains(&b), "bearing {}", b);
+        // Paris - London
+        let b = initial_bearing_deg(48.8566, 2.3522, 51.5074, -0.1278);
+        assert!((b - 330.0).abs() < 0.5, "bearing {}", b);
+    }
+
+    #[cfg(all(feature = "gga", feature = "rmc"))]
+    #[test]
+    fn test_fix_distance() {
+        use crate::{parse, ParseResult};
+
+        let gga = match parse(
+            b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76",
+        ) {
+            Ok(ParseResult::GGA(gga)) => gga,
+            _ => panic!("GGA expected"),
+        };
+        let rmc =
+            match parse(b"$GPRMC,092751.000,A,5321.6802,N,00630.3371,W,0.06,31.66,280511,,,A*45") {
+                Ok(ParseResult::RMC(rmc)) => rmc,
+                _ => panic!("RMC expected"),
+            };
+        let d = fix_distance_m(&gga, &rmc).unwrap();
+        // 0.0001 minute of longitude at 53.36N
+        assert!((d - 0.11).abs() < 0.01, "distance {}", d);
+
+        let no_fix = match parse(b"$GPGGA,,,,,,0,,,,M,,M,,*66") {
+            Ok(ParseResult::GGA(gga)) => gga,
+            _ => panic!("GGA expected"),
+        };
+        assert_eq!(fix_distance_m(&gga, &no_fix), None);
+
+        let b = fix_bearing_deg(&gga, &rmc).unwrap();
+        assert!((b - 90.).abs() < 0.01, "bearing {}", b);
+        assert_eq!(fix_bearing_deg(&no_fix, &rmc), None);
+    }
+}
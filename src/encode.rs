@@ -0,0 +1,589 @@
+use alloc::{format, string::String, vec::Vec};
+
+use chrono::{Datelike, NaiveDate, NaiveTime, Timelike};
+
+use crate::parse::checksum;
+use crate::{
+    FixType, GgaData, GllData, PosSystemIndicator, RmcData, RmcStatusOfFix, Satellite, VtgData,
+};
+
+/// Turn sentence body (talker, type and fields, without `$` and `*`)
+/// into complete sentence: `$`, body, `*HH` checksum and `\r\n`.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::finalize_sentence;
+///
+/// assert_eq!(finalize_sentence("HCHDM,238.5,M"), "$HCHDM,238.5,M*25\r\n");
+/// ```
+pub fn finalize_sentence(body: &str) -> String {
+    format!("${}\r\n", append_checksum(body))
+}
+
+/// Append `*HH` checksum to sentence body, with or without leading `$`/`!`,
+/// for example to send commands to receiver.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::append_checksum;
+///
+/// assert_eq!(append_checksum("$PMTK220,1000"), "$PMTK220,1000*1F");
+/// assert_eq!(append_checksum("HCHDM,238.5,M"), "HCHDM,238.5,M*25");
+/// ```
+pub fn append_checksum(body: &str) -> String {
+    let payload = body.strip_prefix(['$', '!']).unwrap_or(body);
+    format!("{}*{:02X}", body, checksum(payload.as_bytes().iter()))
+}
+
+fn opt_num(v: Option<f32>, width: usize) -> String {
+    v.map(|v| format!("{:0width$.0}", v, width = width))
+        .unwrap_or_default()
+}
+
+fn opt_float(v: Option<f32>) -> String {
+    v.map(|v| format!("{}", v)).unwrap_or_default()
+}
+
+fn hms(t: Option<NaiveTime>) -> String {
+    t.map(|t| {
+        format!(
+            "{:02}{:02}{:02}.{:03}",
+            t.hour(),
+            t.minute(),
+            t.second(),
+            t.nanosecond() / 1_000_000 % 1000
+        )
+    })
+    .unwrap_or_default()
+}
+
+fn dmy(d: Option<NaiveDate>) -> String {
+    d.map(|d| {
+        format!(
+            "{:02}{:02}{:02}",
+            d.day(),
+            d.month(),
+            d.year().rem_euclid(100)
+        )
+    })
+    .unwrap_or_default()
+}
+
+/// "lat,N,lon,E" fields, all four are empty if position is unknown
+fn lat_lon(lat: Option<f64>, lon: Option<f64>) -> String {
+    match (lat, lon) {
+        (Some(lat), Some(lon)) => {
+            let (lat, lat_dir) = to_nmea_lat(lat);
+            let (lon, lon_dir) = to_nmea_lon(lon);
+            format!("{},{},{},{}", lat, lat_dir, lon, lon_dir)
+        }
+        _ => String::from(",,,"),
+    }
+}
+
+fn fix_quality(fix_type: &Option<FixType>) -> char {
+    match fix_type {
+        None | Some(FixType::Invalid) => '0',
+        Some(FixType::Gps) => '1',
+        Some(FixType::DGps) => '2',
+        Some(FixType::Pps) => '3',
+        Some(FixType::RtkFixed) => '4',
+        Some(FixType::RtkFloat) => '5',
+        Some(FixType::Estimated) => '6',
+        Some(FixType::Manual) => '7',
+        Some(FixType::Simulator) => '8',
+    }
+}
+
+/// Serialization of parsed data back to NMEA sentence
+pub trait ToNmea {
+    /// Format `$<talker><type>,...*HH\r\n` sentence with calculated checksum
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nmea::{ToNmea, VtgData};
+    ///
+    /// let vtg = VtgData {
+    ///     true_course: Some(54.7),
+    ///     magnetic_course: Some(34.4),
+    ///     speed_over_ground: Some(5.5),
+    /// };
+    /// assert_eq!(vtg.to_nmea("GP"), "$GPVTG,54.7,T,34.4,M,5.5,N,10.2,K*78\r\n");
+    /// ```
+    fn to_nmea(&self, talker: &str) -> String;
+}
+
+impl ToNmea for GgaData {
+    fn to_nmea(&self, talker: &str) -> String {
+        finalize_sentence(&format!(
+            "{}GGA,{},{},{},{},{},{},M,{},M,{},{}",
+            talker,
+            hms(self.fix_time),
+            lat_lon(self.latitude, self.longitude),
+            fix_quality(&self.fix_type),
+            self.fix_satellites
+                .map(|n| format!("{}", n))
+                .unwrap_or_default(),
+            opt_float(self.hdop),
+            opt_float(self.altitude),
+            opt_float(self.geoid_height),
+            opt_float(self.age_of_dgps),
+            self.dgps_station_id
+                .map(|id| format!("{:04}", id))
+                .unwrap_or_default()
+        ))
+    }
+}
+
+impl ToNmea for RmcData {
+    fn to_nmea(&self, talker: &str) -> String {
+        let (status, mode) = match self.status_of_fix {
+            Some(RmcStatusOfFix::Autonomous) => ('A', 'A'),
+            Some(RmcStatusOfFix::Differential) => ('D', 'D'),
+            Some(RmcStatusOfFix::Invalid) | None => ('V', 'N'),
+        };
+        let variation = match self.magnetic_variation {
+            Some(v) if v < 0. => format!("{},W", -v),
+            Some(v) => format!("{},E", v),
+            None => String::from(","),
+        };
+        let mut body = format!(
+            "{}RMC,{},{},{},{},{},{},{},{}",
+            talker,
+            hms(self.fix_time),
+            status,
+            lat_lon(self.lat, self.lon),
+            opt_float(self.speed_over_ground),
+            opt_float(self.true_course),
+            dmy(self.fix_date),
+            variation,
+            mode
+        );
+        if let Some(nav_status) = self.nav_status {
+            body.push(',');
+            body.push(nav_status);
+        }
+        finalize_sentence(&body)
+    }
+}
+
+impl ToNmea for VtgData {
+    fn to_nmea(&self, talker: &str) -> String {
+        finalize_sentence(&format!(
+            "{}VTG,{},T,{},M,{},N,{},K",
+            talker,
+            opt_float(self.true_course),
+            opt_float(self.magnetic_course),
+            opt_float(self.speed_over_ground),
+            self.speed_over_ground
+                .map(|v| format!("{:.1}", v * 1.852))
+                .unwrap_or_default()
+        ))
+    }
+}
+
+impl ToNmea for GllData {
+    fn to_nmea(&self, talker: &str) -> String {
+        let mode = match self.mode {
+            Some(PosSystemIndicator::Autonomous) => "A",
+            Some(PosSystemIndicator::Differential) => "D",
+            Some(PosSystemIndicator::EstimatedMode) => "E",
+            Some(PosSystemIndicator::ManualInput) => "M",
+            Some(PosSystemIndicator::DataNotValid) => "N",
+            None => "",
+        };
+        finalize_sentence(&format!(
+            "{}GLL,{},{},A,{}",
+            talker,
+            lat_lon(Some(self.latitude), Some(self.longitude)),
+            hms(Some(self.fix_time)),
+            mode
+        ))
+    }
+}
+
+/// Builder of GGA sentence for simulators, fields which are not set
+/// are left empty.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::{FixType, GgaBuilder};
+///
+/// let gga = GgaBuilder::new()
+///     .latitude(53. + 21.6802 / 60.)
+///     .longitude(-(6. + 30.3372 / 60.))
+///     .fix_type(FixType::Gps)
+///     .build();
+/// assert_eq!(gga, "$GPGGA,,5321.6802,N,00630.3372,W,1,,,,M,,M,,*47\r\n");
+/// ```
+#[derive(Debug)]
+pub struct GgaBuilder {
+    talker: String,
+    data: GgaData,
+}
+
+impl Default for GgaBuilder {
+    fn default() -> Self {
+        GgaBuilder::new()
+    }
+}
+
+impl GgaBuilder {
+    pub fn new() -> GgaBuilder {
+        GgaBuilder {
+            talker: String::from("GP"),
+            data: GgaData::default(),
+        }
+    }
+
+    /// Talker id, `GP` by default
+    pub fn talker(mut self, talker: &str) -> Self {
+        self.talker = String::from(talker);
+        self
+    }
+
+    pub fn fix_time(mut self, fix_time: NaiveTime) -> Self {
+        self.data.fix_time = Some(fix_time);
+        self
+    }
+
+    /// Latitude in degrees, negative for south
+    pub fn latitude(mut self, latitude: f64) -> Self {
+        self.data.latitude = Some(latitude);
+        self
+    }
+
+    /// Longitude in degrees, negative for west
+    pub fn longitude(mut self, longitude: f64) -> Self {
+        self.data.longitude = Some(longitude);
+        self
+    }
+
+    pub fn fix_type(mut self, fix_type: FixType) -> Self {
+        self.data.fix_type = Some(fix_type);
+        self
+    }
+
+    pub fn fix_satellites(mut self, fix_satellites: u32) -> Self {
+        self.data.fix_satellites = Some(fix_satellites);
+        self
+    }
+
+    pub fn hdop(mut self, hdop: f32) -> Self {
+        self.data.hdop = Some(hdop);
+        self
+    }
+
+    /// Altitude above mean sea level in meters
+    pub fn altitude(mut self, altitude: f32) -> Self {
+        self.data.altitude = Some(altitude);
+        self
+    }
+
+    /// Height of geoid above WGS84 ellipsoid in meters
+    pub fn geoid_height(mut self, geoid_height: f32) -> Self {
+        self.data.geoid_height = Some(geoid_height);
+        self
+    }
+
+    /// Sentence terminated with checksum and `\r\n`
+    pub fn build(&self) -> String {
+        self.data.to_nmea(&self.talker)
+    }
+}
+
+/// Degrees to `DDmm.mmmm`/`DDDmm.mmmm` string, minutes rounded to 4 digits
+fn to_nmea_deg_min(v: f64, deg_width: usize) -> String {
+    let v = if v < 0. { -v } else { v };
+    let units = (v * 60. * 10_000. + 0.5) as u64;
+    let (deg, min) = (units / 600_000, units % 600_000);
+    format!(
+        "{:0width$}{:02}.{:04}",
+        deg,
+        min / 10_000,
+        min % 10_000,
+        width = deg_width
+    )
+}
+
+/// Format latitude in degrees as NMEA `DDmm.mmmm` string and hemisphere
+/// (`N` or `S`), inverse of latitude parsing.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::to_nmea_lat;
+///
+/// assert_eq!(to_nmea_lat(-(53. + 21.6802 / 60.)), ("5321.6802".to_string(), 'S'));
+/// ```
+pub fn to_nmea_lat(lat: f64) -> (String, char) {
+    (to_nmea_deg_min(lat, 2), if lat < 0. { 'S' } else { 'N' })
+}
+
+/// Format longitude in degrees as NMEA `DDDmm.mmmm` string and hemisphere
+/// (`E` or `W`), inverse of longitude parsing.
+pub fn to_nmea_lon(lon: f64) -> (String, char) {
+    (to_nmea_deg_min(lon, 3), if lon < 0. { 'W' } else { 'E' })
+}
+
+/// Generate GSV sentences for satellites of one constellation,
+/// 4 satellites per sentence.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::{encode_gsv, GnssType, Satellite};
+///
+/// let sats = [Satellite::new(GnssType::Gps, 10, Some(63.), Some(137.), Some(17.))];
+/// assert_eq!(encode_gsv(&sats, "GP"), vec!["$GPGSV,1,1,01,10,63,137,17*4F\r\n"]);
+/// ```
+pub fn encode_gsv(sats: &[Satellite], talker: &str) -> Vec<String> {
+    let number_of_sentences = if sats.is_empty() {
+        1
+    } else {
+        sats.len().div_ceil(4)
+    };
+    (0..number_of_sentences)
+        .map(|i| {
+            let mut body = format!(
+                "{}GSV,{},{},{:02}",
+                talker,
+                number_of_sentences,
+                i + 1,
+                sats.len()
+            );
+            for sat in sats.iter().skip(i * 4).take(4) {
+                body.push_str(&format!(
+                    ",{:02},{},{},{}",
+                    sat.prn,
+                    opt_num(sat.elevation, 2),
+                    opt_num(sat.azimuth, 3),
+                    opt_num(sat.snr, 2)
+                ));
+            }
+            finalize_sentence(&body)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finalize_sentence() {
+        assert_eq!(
+            finalize_sentence("GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,"),
+            "$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76\r\n"
+        );
+        assert_eq!(
+            finalize_sentence("GNGSA,A,1,,,,,,,,,,,,,99.99,99.99,99.99"),
+            "$GNGSA,A,1,,,,,,,,,,,,,99.99,99.99,99.99*2E\r\n"
+        );
+        assert_eq!(finalize_sentence("PMTK220,1000"), "$PMTK220,1000*1F\r\n");
+    }
+
+    #[test]
+    fn test_append_checksum() {
+        let gga = "$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,";
+        assert_eq!(append_checksum(gga), format!("{}*76", gga));
+        assert_eq!(append_checksum(&gga[1..]), format!("{}*76", &gga[1..]));
+        assert_eq!(
+            append_checksum("!AIVDM,1,1,,A,13aGmP0P00PD;88MD5MTDww@2<0L,0"),
+            "!AIVDM,1,1,,A,13aGmP0P00PD;88MD5MTDww@2<0L,0*23"
+        );
+        assert_eq!(append_checksum(""), "*00");
+    }
+
+    #[test]
+    fn test_to_nmea_lat_lon() {
+        assert_eq!(
+            to_nmea_lat(53. + 21.6802 / 60.),
+            ("5321.6802".to_string(), 'N')
+        );
+        assert_eq!(
+            to_nmea_lon(-(6. + 30.3372 / 60.)),
+            ("00630.3372".to_string(), 'W')
+        );
+        assert_eq!(to_nmea_lat(0.), ("0000.0000".to_string(), 'N'));
+        assert_eq!(to_nmea_lon(180.), ("18000.0000".to_string(), 'E'));
+        // minutes rounded up to 60 carry into degrees
+        assert_eq!(
+            to_nmea_lat(-(9. + 59.99999 / 60.)),
+            ("1000.0000".to_string(), 'S')
+        );
+    }
+
+    #[cfg(any(
+        feature = "gga",
+        feature = "ggk",
+        feature = "gll",
+        feature = "gns",
+        feature = "rma",
+        feature = "rmc",
+        feature = "tll"
+    ))]
+    #[test]
+    fn test_to_nmea_lat_lon_parse_back() {
+        use crate::parse::do_parse_lat_lon;
+
+        for &(lat, lon) in &[
+            (0.0, 0.0),
+            (55.751667, 37.617778),
+            (-33.856784, 151.215297),
+            (89.999999, -179.999999),
+            (-12.5, -0.000001),
+        ] {
+            let (lat_s, lat_dir) = to_nmea_lat(lat);
+            let (lon_s, lon_dir) = to_nmea_lon(lon);
+            let s = format!("{},{},{},{}", lat_s, lat_dir, lon_s, lon_dir);
+            let (_, (new_lat, new_lon)) = do_parse_lat_lon(s.as_bytes()).unwrap();
+            assert!((new_lat - lat).abs() < 1e-5, "{} -> {}", lat, s);
+            assert!((new_lon - lon).abs() < 1e-5, "{} -> {}", lon, s);
+        }
+    }
+
+    #[cfg(all(feature = "gga", feature = "rmc", feature = "vtg", feature = "gll"))]
+    #[test]
+    fn test_to_nmea_round_trip() {
+        use crate::{parse, ParseResult};
+
+        for line in &[
+            "$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76\r\n",
+            "$GPGGA,,,,,,0,,,,M,,M,,*66\r\n",
+            "$GPRMC,092750.000,A,5321.6802,N,00630.3372,W,0.02,31.66,280511,,,A*43\r\n",
+            "$GNRMC,225446.330,D,4916.4500,N,12311.1200,W,0.5,54.7,191194,,,D,S*20\r\n",
+            "$GPRMC,225446.000,A,4916.4500,N,12311.1200,W,0.5,54.7,191194,20.3,W,A*09\r\n",
+            "$GPVTG,54.7,T,,M,5.5,N,10.2,K*65\r\n",
+            "$GPGLL,4916.4500,N,12311.1200,W,225444.000,A,A*42\r\n",
+        ] {
+            let talker = &line[1..3];
+            let encoded = match parse(line.trim_end().as_bytes()).unwrap() {
+                ParseResult::GGA(data) => data.to_nmea(talker),
+                ParseResult::RMC(data) => data.to_nmea(talker),
+                ParseResult::VTG(data) => data.to_nmea(talker),
+                ParseResult::GLL(data) => data.to_nmea(talker),
+                _ => panic!("unexpected sentence {}", line),
+            };
+            assert_eq!(&encoded, line);
+        }
+
+        // values which don't survive formatting as is
+        let gga = GgaData {
+            fix_time: NaiveTime::from_hms_milli_opt(3, 37, 45, 500),
+            fix_type: Some(FixType::RtkFloat),
+            latitude: Some(-(56. + 50.82344 / 60.)),
+            longitude: Some(35. + 48.9778 / 60.),
+            fix_satellites: Some(12),
+            hdop: Some(0.7),
+            altitude: Some(-10.25),
+            geoid_height: None,
+            age_of_dgps: Some(1.5),
+            dgps_station_id: Some(23),
+        };
+        match parse(gga.to_nmea("GN").trim_end().as_bytes()).unwrap() {
+            ParseResult::GGA(data) => {
+                assert_eq!(data.fix_time, gga.fix_time);
+                assert_eq!(data.fix_type, gga.fix_type);
+                assert!((data.latitude.unwrap() - gga.latitude.unwrap()).abs() < 1e-5);
+                assert!((data.longitude.unwrap() - gga.longitude.unwrap()).abs() < 1e-5);
+                assert_eq!(
+                    (
+                        data.fix_satellites,
+                        data.hdop,
+                        data.altitude,
+                        data.geoid_height,
+                        data.age_of_dgps,
+                        data.dgps_station_id
+                    ),
+                    (Some(12), Some(0.7), Some(-10.25), None, Some(1.5), Some(23))
+                );
+            }
+            _ => panic!("GGA expected"),
+        }
+    }
+
+    #[cfg(feature = "gga")]
+    #[test]
+    fn test_gga_builder() {
+        use crate::{parse, ParseResult};
+
+        let line = GgaBuilder::new()
+            .talker("GN")
+            .fix_time(NaiveTime::from_hms_milli_opt(9, 27, 50, 250).unwrap())
+            .latitude(-(33. + 51.4070 / 60.))
+            .longitude(151. + 12.9178 / 60.)
+            .fix_type(FixType::DGps)
+            .fix_satellites(9)
+            .hdop(0.9)
+            .altitude(58.3)
+            .geoid_height(22.1)
+            .build();
+        assert!(line.starts_with("$GNGGA,092750.250,3351.4070,S,15112.9178,E,2,9,0.9,58.3,M,"));
+        match parse(line.trim_end().as_bytes()).unwrap() {
+            ParseResult::GGA(data) => {
+                assert_eq!(data.fix_time, NaiveTime::from_hms_milli_opt(9, 27, 50, 250));
+                assert_eq!(data.fix_type, Some(FixType::DGps));
+                assert!((data.latitude.unwrap() + (33. + 51.4070 / 60.)).abs() < 1e-7);
+                assert!((data.longitude.unwrap() - (151. + 12.9178 / 60.)).abs() < 1e-7);
+                assert_eq!(data.fix_satellites, Some(9));
+                assert_eq!(data.hdop, Some(0.9));
+                assert_eq!(data.altitude, Some(58.3));
+                assert_eq!(data.geoid_height, Some(22.1));
+            }
+            _ => panic!("GGA expected"),
+        }
+
+        let line = GgaBuilder::new().altitude(12.5).build();
+        assert_eq!(line, "$GPGGA,,,,,,0,,,12.5,M,,M,,*7E\r\n");
+        match parse(line.trim_end().as_bytes()).unwrap() {
+            ParseResult::GGA(data) => {
+                assert_eq!(data.fix_time, None);
+                assert_eq!(data.latitude, None);
+                assert_eq!(data.fix_type, Some(FixType::Invalid));
+                assert_eq!(data.altitude, Some(12.5));
+            }
+            _ => panic!("GGA expected"),
+        }
+    }
+
+    #[cfg(feature = "gsv")]
+    #[test]
+    fn test_encode_gsv() {
+        use crate::{parse, GnssType, GsvAssembler, ParseResult};
+
+        let sats: Vec<Satellite> = (1..=9)
+            .map(|prn| {
+                Satellite::new(
+                    GnssType::Gps,
+                    prn,
+                    Some(10. + prn as f32),
+                    Some(100. + prn as f32),
+                    if prn % 2 == 0 { None } else { Some(30.) },
+                )
+            })
+            .collect();
+        let lines = encode_gsv(&sats, "GP");
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[0],
+            "$GPGSV,3,1,09,01,11,101,30,02,12,102,,03,13,103,30,04,14,104,*76\r\n"
+        );
+        assert_eq!(lines[2], "$GPGSV,3,3,09,09,19,109,30*4A\r\n");
+
+        let mut assembler = GsvAssembler::new();
+        for line in &lines {
+            match parse(line.as_bytes()).unwrap() {
+                ParseResult::GSV(data) => assembler.merge(data).unwrap(),
+                _ => panic!("GSV expected"),
+            }
+        }
+        assert_eq!(assembler.satellites(), sats);
+
+        assert_eq!(encode_gsv(&[], "GP"), vec!["$GPGSV,1,1,00*79\r\n"]);
+    }
+}
@@ -0,0 +1,132 @@
+use alloc::string::String;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use nom::error::ErrorKind;
+
+use crate::SentenceType;
+
+/// Error returned by parsers of this crate
+#[derive(Debug, Clone, PartialEq)]
+pub enum NmeaError {
+    /// Sentence is longer than receivers are allowed to emit
+    TooLong,
+    /// Checksum from sentence doesn't match calculated one
+    ChecksumMismatch { expected: u8, found: u8 },
+    /// Talker id doesn't map to any known GNSS system
+    UnknownTalker,
+    /// Message id is not a valid header
+    UnknownSentenceType,
+    /// Sentence passed to parser of other sentence type
+    UnexpectedSentence {
+        expected: SentenceType,
+        got: [u8; 3],
+    },
+    /// Sentence has valid type, but it's parsing is not implemented
+    Unsupported(SentenceType),
+    /// Sentence doesn't match its grammar
+    ParsingError(nom::Err<ErrorKind>),
+    /// Some field of sentence has unacceptable value
+    InvalidField(&'static str),
+    /// Sentence doesn't fit into data collected from previous sentences
+    InvalidSequence(&'static str),
+    /// Sentence ends before all required fields
+    Incomplete,
+    /// Sentence reports that there is no position fix,
+    /// returned only if `ParseOptions::skip_invalid_fix` is set
+    NoFix,
+    /// Latitude with hemisphere applied is out of `[-90, 90]` degrees
+    InvalidLatitude,
+    /// Longitude with hemisphere applied is out of `[-180, 180]` degrees
+    InvalidLongitude,
+    /// Reading of sentences failed
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
+}
+
+impl NmeaError {
+    #[cfg(any(
+        feature = "gga",
+        feature = "ggk",
+        feature = "gll",
+        feature = "gns",
+        feature = "gsa",
+        feature = "gst",
+        feature = "gsv",
+        feature = "hdm",
+        feature = "mta",
+        feature = "mtw",
+        feature = "rma",
+        feature = "rmc",
+        feature = "tll",
+        feature = "vdr",
+        feature = "vtg",
+        feature = "ztg"
+    ))]
+    pub(crate) fn unexpected_sentence(expected: SentenceType, got: &[u8]) -> NmeaError {
+        let mut id = [0; 3];
+        for (to, from) in id.iter_mut().zip(got) {
+            *to = *from;
+        }
+        NmeaError::UnexpectedSentence { expected, got: id }
+    }
+}
+
+impl<'a> From<nom::Err<(&'a [u8], ErrorKind)>> for NmeaError {
+    fn from(err: nom::Err<(&'a [u8], ErrorKind)>) -> Self {
+        match err {
+            nom::Err::Incomplete(_) => NmeaError::Incomplete,
+            nom::Err::Error((_, kind)) => NmeaError::ParsingError(nom::Err::Error(kind)),
+            nom::Err::Failure((_, kind)) => NmeaError::ParsingError(nom::Err::Failure(kind)),
+        }
+    }
+}
+
+impl fmt::Display for NmeaError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NmeaError::TooLong => write!(f, "Too long message"),
+            NmeaError::ChecksumMismatch { expected, found } => write!(
+                f,
+                "Checksum mismatch: calculated {:02X}, sentence has {:02X}",
+                expected, found
+            ),
+            NmeaError::UnknownTalker => write!(f, "Unknown GNSS type in talker id"),
+            NmeaError::UnknownSentenceType => write!(f, "invalid header"),
+            NmeaError::UnexpectedSentence { expected, got } => write!(
+                f,
+                "{:?} message should starts with $..{:?}, got $..{}",
+                expected,
+                expected,
+                String::from_utf8_lossy(got)
+            ),
+            NmeaError::Unsupported(msg_id) => {
+                write!(f, "Unknown or implemented sentence type: {:?}", msg_id)
+            }
+            NmeaError::ParsingError(nom::Err::Error(kind))
+            | NmeaError::ParsingError(nom::Err::Failure(kind)) => {
+                write!(f, "{}", kind.description())
+            }
+            NmeaError::ParsingError(nom::Err::Incomplete(_)) | NmeaError::Incomplete => {
+                write!(f, "Incomplete nmea sentence")
+            }
+            NmeaError::InvalidField(msg) | NmeaError::InvalidSequence(msg) => write!(f, "{}", msg),
+            NmeaError::NoFix => write!(f, "Sentence reports no fix"),
+            NmeaError::InvalidLatitude => write!(f, "Latitude out of range"),
+            NmeaError::InvalidLongitude => write!(f, "Longitude out of range"),
+            #[cfg(feature = "std")]
+            NmeaError::Io(kind) => write!(f, "I/O error: {}", kind),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for NmeaError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            NmeaError::ParsingError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
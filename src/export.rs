@@ -0,0 +1,29 @@
+//! Shared parts of CSV, GPX, JSON and KML export
+
+use alloc::string::String;
+use core::fmt;
+
+/// Run `write` into new `String`, writing into `String` can't fail,
+/// so output is complete
+pub(crate) fn write_string<F: FnOnce(&mut String) -> fmt::Result>(write: F) -> String {
+    let mut out = String::new();
+    let _ = write(&mut out);
+    out
+}
+
+/// Parse results of short track: GGA and RMC of one fix, GSA which
+/// exporters skip, GGA without fix, GGA without altitude and RMC alone
+#[cfg(all(test, feature = "gga", feature = "rmc"))]
+pub(crate) fn parsed_track() -> alloc::vec::Vec<crate::ParseResult> {
+    [
+        &b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76"[..],
+        b"$GPRMC,092750.000,A,5321.6802,N,00630.3372,W,0.02,31.66,280511,,,A*43",
+        b"$GPGSA,A,3,10,07,05,02,29,04,08,13,,,,,1.72,1.03,1.38*0A",
+        b"$GPGGA,,,,,,0,,,,M,,M,,*66",
+        b"$GPGGA,092751.000,5321.6802,N,00630.3371,W,1,8,1.03,,M,,M,,*76",
+        b"$GPRMC,092752.000,A,5321.6802,N,00630.3370,W,0.06,31.66,280511,,,A*47",
+    ]
+    .iter()
+    .map(|line| crate::parse(line).unwrap())
+    .collect()
+}
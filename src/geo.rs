@@ -0,0 +1,72 @@
+//! Conversion of positions to `geo_types`, x of point is longitude, y is latitude
+
+use geo_types::Point;
+
+use crate::{GgaData, GllData, RmcData};
+
+fn point(lat: Option<f64>, lon: Option<f64>) -> Option<Point<f64>> {
+    Some(Point::new(lon?, lat?))
+}
+
+impl From<&GgaData> for Option<Point<f64>> {
+    fn from(gga: &GgaData) -> Self {
+        point(gga.latitude, gga.longitude)
+    }
+}
+
+impl From<&RmcData> for Option<Point<f64>> {
+    fn from(rmc: &RmcData) -> Self {
+        point(rmc.lat, rmc.lon)
+    }
+}
+
+impl From<&GllData> for Option<Point<f64>> {
+    fn from(gll: &GllData) -> Self {
+        point(Some(gll.latitude), Some(gll.longitude))
+    }
+}
+
+#[cfg(all(test, feature = "all-sentences"))]
+mod tests {
+    use super::*;
+    use crate::{parse, ParseResult};
+
+    #[test]
+    fn test_point_from_gga() {
+        let gga = match parse(
+            b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76",
+        ) {
+            Ok(ParseResult::GGA(gga)) => gga,
+            _ => panic!("GGA expected"),
+        };
+        let p: Option<Point<f64>> = (&gga).into();
+        assert_eq!(
+            p,
+            Some(Point::new(-(6. + 30.3372 / 60.), 53. + 21.6802 / 60.))
+        );
+
+        let gga = match parse(b"$GPGGA,,,,,,0,,,,M,,M,,*66") {
+            Ok(ParseResult::GGA(gga)) => gga,
+            _ => panic!("GGA expected"),
+        };
+        assert_eq!(Option::<Point<f64>>::from(&gga), None);
+    }
+
+    #[test]
+    fn test_point_from_rmc_gll() {
+        match parse(b"$GPRMC,092750.000,A,5321.6802,N,00630.3372,W,0.02,31.66,280511,,,A*43") {
+            Ok(ParseResult::RMC(rmc)) => {
+                let p: Option<Point<f64>> = (&rmc).into();
+                assert_eq!(p.unwrap().y(), 53. + 21.6802 / 60.);
+            }
+            _ => panic!("RMC expected"),
+        }
+        match parse(b"$GPGLL,4916.4500,N,12311.1200,W,225444.000,A,A*42") {
+            Ok(ParseResult::GLL(gll)) => {
+                let p: Option<Point<f64>> = (&gll).into();
+                assert_eq!(p.unwrap().x(), -(123. + 11.12 / 60.));
+            }
+            _ => panic!("GLL expected"),
+        }
+    }
+}
@@ -0,0 +1,165 @@
+//! GPX 1.1 track export, for tools which don't read NMEA logs
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Write};
+
+use chrono::{DateTime, NaiveDate, NaiveTime, SecondsFormat, Utc};
+
+use crate::export::write_string;
+use crate::ParseResult;
+
+/// Point of GPX track
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackPoint {
+    /// Latitude in degrees, negative for south
+    pub lat: f64,
+    /// Longitude in degrees, negative for west
+    pub lon: f64,
+    /// Altitude above mean sea level in meters
+    pub elevation: Option<f32>,
+    pub time: Option<DateTime<Utc>>,
+}
+
+/// GPX document with one track of one segment through `fixes`
+///
+/// # Examples
+///
+/// ```
+/// use nmea::{to_gpx, TrackPoint};
+///
+/// let gpx = to_gpx(&[TrackPoint {
+///     lat: 53.5,
+///     lon: -6.5,
+///     elevation: Some(61.7),
+///     time: None,
+/// }]);
+/// assert!(gpx.contains(r#"<trkpt lat="53.5" lon="-6.5"><ele>61.7</ele></trkpt>"#));
+/// ```
+pub fn to_gpx(fixes: &[TrackPoint]) -> String {
+    write_string(|out| write_gpx(out, fixes))
+}
+
+/// Track points from GGA and RMC sentences, results without position
+/// are skipped.
+///
+/// GGA and RMC of the same fix time are merged into one point:
+/// elevation comes from GGA and date from RMC. GGA time gets date of
+/// the last RMC, so GGA before the first RMC has no time.
+pub fn track_points(results: &[ParseResult]) -> Vec<TrackPoint> {
+    let mut points: Vec<(Option<NaiveTime>, TrackPoint)> = Vec::new();
+    let mut date = None;
+    for result in results {
+        let (fix_time, point) = match track_point(result, &mut date) {
+            Some(v) => v,
+            None => continue,
+        };
+        match points.last_mut() {
+            Some((prev_time, prev)) if fix_time.is_some() && *prev_time == fix_time => {
+                prev.elevation = prev.elevation.or(point.elevation);
+                prev.time = prev.time.or(point.time);
+            }
+            _ => points.push((fix_time, point)),
+        }
+    }
+    points.into_iter().map(|(_, point)| point).collect()
+}
+
+// `date` is used by GGA and RMC only
+#[cfg_attr(not(any(feature = "gga", feature = "rmc")), allow(unused_variables))]
+fn track_point(
+    result: &ParseResult,
+    date: &mut Option<NaiveDate>,
+) -> Option<(Option<NaiveTime>, TrackPoint)> {
+    match result {
+        #[cfg(feature = "gga")]
+        ParseResult::GGA(gga) => Some((
+            gga.fix_time,
+            TrackPoint {
+                lat: gga.latitude?,
+                lon: gga.longitude?,
+                elevation: gga.altitude,
+                time: date.and_then(|date| gga.datetime_with_date(date)),
+            },
+        )),
+        #[cfg(feature = "rmc")]
+        ParseResult::RMC(rmc) => {
+            *date = rmc.fix_date.or(*date);
+            Some((
+                rmc.fix_time,
+                TrackPoint {
+                    lat: rmc.lat?,
+                    lon: rmc.lon?,
+                    elevation: None,
+                    time: rmc.datetime_utc(),
+                },
+            ))
+        }
+        _ => None,
+    }
+}
+
+fn write_gpx<W: Write>(w: &mut W, fixes: &[TrackPoint]) -> fmt::Result {
+    w.write_str(concat!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+        "\n",
+        r#"<gpx version="1.1" creator="nmea" xmlns="http://www.topografix.com/GPX/1/1">"#,
+        "\n<trk><trkseg>\n"
+    ))?;
+    for point in fixes {
+        write!(w, r#"<trkpt lat="{}" lon="{}">"#, point.lat, point.lon)?;
+        if let Some(elevation) = point.elevation {
+            write!(w, "<ele>{}</ele>", elevation)?;
+        }
+        if let Some(time) = point.time {
+            write!(
+                w,
+                "<time>{}</time>",
+                time.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+            )?;
+        }
+        w.write_str("</trkpt>\n")?;
+    }
+    w.write_str("</trkseg></trk>\n</gpx>\n")
+}
+
+#[cfg(all(test, feature = "gga", feature = "rmc"))]
+mod tests {
+    use super::*;
+    use crate::export::parsed_track;
+
+    #[test]
+    fn test_to_gpx() {
+        let points = track_points(&parsed_track());
+        assert_eq!(points.len(), 3);
+        assert_eq!(points[0].elevation, Some(61.7));
+        assert_eq!(
+            points[0].time.map(|t| t.to_rfc3339()),
+            Some("2011-05-28T09:27:50+00:00".into())
+        );
+        // GGA time with date of previous RMC
+        assert_eq!(
+            points[1].time.map(|t| t.to_rfc3339()),
+            Some("2011-05-28T09:27:51+00:00".into())
+        );
+        assert_eq!(points[1].elevation, None);
+        assert_eq!(points[2].elevation, None);
+
+        let gpx = to_gpx(&points);
+        assert!(gpx.starts_with("<?xml"));
+        assert!(gpx.contains("<gpx version=\"1.1\""));
+        assert!(gpx.contains("<trk><trkseg>"));
+        assert!(gpx.ends_with("</trkseg></trk>\n</gpx>\n"));
+        assert_eq!(gpx.matches("<trkpt ").count(), 3);
+        assert_eq!(gpx.matches("</trkpt>").count(), 3);
+        assert_eq!(gpx.matches("<time>2011-05-28T09:27:5").count(), 3);
+        assert_eq!(gpx.matches("<ele>").count(), 1);
+        assert!(gpx.contains(
+            "<trkpt lat=\"53.361336666666666\" lon=\"-6.50562\">\
+             <ele>61.7</ele><time>2011-05-28T09:27:50Z</time></trkpt>"
+        ));
+
+        let gpx = to_gpx(&[]);
+        assert!(!gpx.contains("<trkpt"));
+        assert!(gpx.contains("<trkseg>"));
+    }
+}
@@ -0,0 +1,128 @@
+//! Normalization of angles and conversions between magnetic and true headings
+
+/// Wrap angle in degrees into `[0, 360)`, for any finite input,
+/// including negative ones and several full turns
+pub fn normalize_degrees(deg: f32) -> f32 {
+    let deg = deg % 360.;
+    let deg = if deg < 0. { deg + 360. } else { deg };
+    // adding 360 to tiny negative value rounds to 360
+    if deg >= 360. {
+        0.
+    } else {
+        deg
+    }
+}
+
+/// `normalize_degrees` for `f64` bearings
+#[cfg(feature = "std")]
+pub(crate) fn normalize_degrees_f64(deg: f64) -> f64 {
+    let deg = deg % 360.;
+    let deg = if deg < 0. { deg + 360. } else { deg };
+    if deg >= 360. {
+        0.
+    } else {
+        deg
+    }
+}
+
+/// Heading relative to true north in degrees `[0, 360)` from magnetic one.
+///
+/// `variation` is magnitude of magnetic variation as in sentences,
+/// east variation is added and west one is subtracted.
+pub fn true_heading(magnetic: f32, variation: f32, variation_east: bool) -> f32 {
+    if variation_east {
+        normalize_degrees(magnetic + variation)
+    } else {
+        normalize_degrees(magnetic - variation)
+    }
+}
+
+/// Heading relative to magnetic north in degrees `[0, 360)`,
+/// inverse of `true_heading`
+pub fn magnetic_heading(true_heading: f32, variation: f32, variation_east: bool) -> f32 {
+    if variation_east {
+        normalize_degrees(true_heading - variation)
+    } else {
+        normalize_degrees(true_heading + variation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_degrees() {
+        for &(deg, expected) in &[
+            (-10., 350.),
+            (370., 10.),
+            (360., 0.),
+            (0., 0.),
+            (359.5, 359.5),
+            (720., 0.),
+            (730., 10.),
+            (-730., 350.),
+            (-360., 0.),
+        ] {
+            assert_eq!(normalize_degrees(deg), expected, "{}", deg);
+        }
+        assert_eq!(normalize_degrees(-1e-8), 0.);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_normalize_degrees_f64() {
+        for &(deg, expected) in &[(-10., 350.), (370., 10.), (360., 0.), (730., 10.)] {
+            assert_eq!(normalize_degrees_f64(deg), expected, "{}", deg);
+        }
+        assert_eq!(normalize_degrees_f64(-1e-300), 0.);
+    }
+
+    #[test]
+    fn test_true_heading() {
+        for &(magnetic, variation, east, expected) in &[
+            (100., 5., true, 105.),
+            (100., 5., false, 95.),
+            // crossing 0/360 boundary
+            (358., 4.5, true, 2.5),
+            (2., 4.5, false, 357.5),
+            (355., 5., true, 0.),
+        ] {
+            let heading = true_heading(magnetic, variation, east);
+            assert!(
+                (heading - expected).abs() < 1e-4,
+                "true heading {} to {}",
+                heading,
+                expected
+            );
+            let back = magnetic_heading(heading, variation, east);
+            assert!(
+                (back - magnetic % 360.).abs() < 1e-4,
+                "magnetic heading {} to {}",
+                back,
+                magnetic
+            );
+        }
+    }
+
+    #[test]
+    fn test_magnetic_heading() {
+        for &(heading, variation, east, expected) in &[
+            (105., 5., true, 100.),
+            (3., 10., true, 353.),
+            (357., 10., false, 7.),
+            (0., 0., false, 0.),
+        ] {
+            let magnetic = magnetic_heading(heading, variation, east);
+            assert!(
+                (magnetic - expected).abs() < 1e-4,
+                "magnetic heading {} to {}",
+                magnetic,
+                expected
+            );
+        }
+        assert_eq!(magnetic_heading(1., 1e-8, false), 1.);
+        let wrapped = true_heading(0., 1e-8, false);
+        assert!((0. ..360.).contains(&wrapped), "heading {}", wrapped);
+    }
+}
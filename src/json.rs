@@ -0,0 +1,540 @@
+//! Compact JSON and GeoJSON output without `serde`, for constrained targets.
+//! Field names match ones produced with `serde` feature.
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Write};
+
+use chrono::{NaiveDate, NaiveTime};
+
+use crate::export::write_string;
+use crate::parse::{GsaMode1, GsaMode2};
+use crate::{
+    FixType, GgkQuality, GnssType, ParseResult, PosSystemIndicator, RmcStatusOfFix, Satellite,
+    SentenceType, TalkerId, TemperatureUnit,
+};
+
+/// Serialize parse result into JSON object, tagged by `type` field
+/// like with `serde` feature. `None` fields are `null`.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "hdm")] {
+/// use nmea::{parse, to_json};
+///
+/// let hdm = parse(b"$HCHDM,238.5,M*25").unwrap();
+/// assert_eq!(to_json(&hdm), r#"{"type":"HDM","heading":238.5}"#);
+/// # }
+/// ```
+pub fn to_json(result: &ParseResult) -> String {
+    write_string(|out| write_result(out, result))
+}
+
+/// GeoJSON `Feature` with `LineString` geometry through points given as
+/// `(latitude, longitude)` in degrees. Note that GeoJSON itself stores
+/// longitude first. GeoJSON requires at least two points in line string,
+/// this is left to caller.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::to_geojson_linestring;
+///
+/// assert_eq!(
+///     to_geojson_linestring(&[(53.5, -6.5), (53.5, -6.25)]),
+///     r#"{"type":"Feature","geometry":{"type":"LineString","coordinates":[[-6.5,53.5],[-6.25,53.5]]},"properties":{}}"#
+/// );
+/// ```
+pub fn to_geojson_linestring(fixes: &[(f64, f64)]) -> String {
+    write_string(|out| write_geojson_linestring(out, fixes))
+}
+
+/// `to_geojson_linestring` of positions reported by GGA, RMC, GLL, GNS
+/// and RMA sentences, results without position are skipped
+pub fn track_to_geojson(results: &[ParseResult]) -> String {
+    let fixes: Vec<(f64, f64)> = results.iter().filter_map(result_position).collect();
+    to_geojson_linestring(&fixes)
+}
+
+fn result_position(result: &ParseResult) -> Option<(f64, f64)> {
+    match result {
+        #[cfg(feature = "gga")]
+        ParseResult::GGA(gga) => Some((gga.latitude?, gga.longitude?)),
+        #[cfg(feature = "rmc")]
+        ParseResult::RMC(rmc) => Some((rmc.lat?, rmc.lon?)),
+        #[cfg(feature = "gll")]
+        ParseResult::GLL(gll) => Some((gll.latitude, gll.longitude)),
+        #[cfg(feature = "gns")]
+        ParseResult::GNS(gns) => Some((gns.latitude?, gns.longitude?)),
+        #[cfg(feature = "rma")]
+        ParseResult::RMA(rma) => Some((rma.latitude?, rma.longitude?)),
+        _ => None,
+    }
+}
+
+fn write_geojson_linestring<W: Write>(w: &mut W, fixes: &[(f64, f64)]) -> fmt::Result {
+    w.write_str(r#"{"type":"Feature","geometry":{"type":"LineString","coordinates":["#)?;
+    for (i, &(lat, lon)) in fixes.iter().enumerate() {
+        if i > 0 {
+            w.write_char(',')?;
+        }
+        [lon, lat][..].write_json(w)?;
+    }
+    w.write_str(r#"]},"properties":{}}"#)
+}
+
+trait JsonValue {
+    fn write_json<W: Write>(&self, w: &mut W) -> fmt::Result;
+}
+
+impl<T: JsonValue + ?Sized> JsonValue for &T {
+    fn write_json<W: Write>(&self, w: &mut W) -> fmt::Result {
+        (**self).write_json(w)
+    }
+}
+
+impl<T: JsonValue> JsonValue for Option<T> {
+    fn write_json<W: Write>(&self, w: &mut W) -> fmt::Result {
+        match self {
+            Some(v) => v.write_json(w),
+            None => w.write_str("null"),
+        }
+    }
+}
+
+impl<T: JsonValue> JsonValue for [T] {
+    fn write_json<W: Write>(&self, w: &mut W) -> fmt::Result {
+        w.write_char('[')?;
+        for (i, v) in self.iter().enumerate() {
+            if i > 0 {
+                w.write_char(',')?;
+            }
+            v.write_json(w)?;
+        }
+        w.write_char(']')
+    }
+}
+
+macro_rules! impl_json_number {
+    ($($t:ty),*) => {
+        $(impl JsonValue for $t {
+            fn write_json<W: Write>(&self, w: &mut W) -> fmt::Result {
+                write!(w, "{}", self)
+            }
+        })*
+    };
+}
+
+impl_json_number!(bool, u8, u16, u32);
+
+macro_rules! impl_json_float {
+    ($($t:ty),*) => {
+        $(impl JsonValue for $t {
+            fn write_json<W: Write>(&self, w: &mut W) -> fmt::Result {
+                // JSON has no NaN and infinity, `Debug` keeps `.0`
+                // of whole numbers like `serde_json` does
+                if self.is_finite() {
+                    write!(w, "{:?}", self)
+                } else {
+                    w.write_str("null")
+                }
+            }
+        })*
+    };
+}
+
+impl_json_float!(f32, f64);
+
+impl JsonValue for str {
+    fn write_json<W: Write>(&self, w: &mut W) -> fmt::Result {
+        w.write_char('"')?;
+        for c in self.chars() {
+            match c {
+                '"' => w.write_str("\\\"")?,
+                '\\' => w.write_str("\\\\")?,
+                '\n' => w.write_str("\\n")?,
+                '\r' => w.write_str("\\r")?,
+                '\t' => w.write_str("\\t")?,
+                c if u32::from(c) < 0x20 => write!(w, "\\u{:04x}", u32::from(c))?,
+                c => w.write_char(c)?,
+            }
+        }
+        w.write_char('"')
+    }
+}
+
+impl JsonValue for String {
+    fn write_json<W: Write>(&self, w: &mut W) -> fmt::Result {
+        self.as_str().write_json(w)
+    }
+}
+
+impl JsonValue for char {
+    fn write_json<W: Write>(&self, w: &mut W) -> fmt::Result {
+        self.encode_utf8(&mut [0; 4]).write_json(w)
+    }
+}
+
+/// Types serialized as string of their `Display` or `Debug` output,
+/// which doesn't need escaping
+macro_rules! impl_json_as_string {
+    ($fmt:literal: $($t:ty),*) => {
+        $(impl JsonValue for $t {
+            fn write_json<W: Write>(&self, w: &mut W) -> fmt::Result {
+                write!(w, concat!("\"", $fmt, "\""), self)
+            }
+        })*
+    };
+}
+
+impl_json_as_string!("{}": NaiveTime, NaiveDate);
+impl_json_as_string!("{:?}": FixType, GnssType, GsaMode1, GsaMode2, PosSystemIndicator,
+    RmcStatusOfFix, SentenceType, TemperatureUnit);
+
+/// Variants with data are objects with variant name as the only key,
+/// like externally tagged enums of `serde`
+impl JsonValue for TalkerId {
+    fn write_json<W: Write>(&self, w: &mut W) -> fmt::Result {
+        match *self {
+            TalkerId::Ais(a, b) => {
+                let mut obj = Object::begin(w)?;
+                obj.field("Ais", &[a, b][..])?;
+                obj.end()
+            }
+            TalkerId::Other(id) => {
+                let mut obj = Object::begin(w)?;
+                obj.field("Other", &id[..])?;
+                obj.end()
+            }
+            _ => write!(w, "\"{:?}\"", self),
+        }
+    }
+}
+
+impl JsonValue for GgkQuality {
+    fn write_json<W: Write>(&self, w: &mut W) -> fmt::Result {
+        match *self {
+            GgkQuality::Other(x) => {
+                let mut obj = Object::begin(w)?;
+                obj.field("Other", x)?;
+                obj.end()
+            }
+            _ => write!(w, "\"{:?}\"", self),
+        }
+    }
+}
+
+impl JsonValue for Satellite {
+    fn write_json<W: Write>(&self, w: &mut W) -> fmt::Result {
+        let mut obj = Object::begin(w)?;
+        obj.field("gnss_type", self.gnss_type())?;
+        obj.field("prn", self.prn())?;
+        obj.field("elevation", self.elevation())?;
+        obj.field("azimuth", self.azimuth())?;
+        obj.field("snr", self.snr())?;
+        obj.field("used_in_fix", self.used_in_fix())?;
+        obj.end()
+    }
+}
+
+struct Object<'a, W: Write> {
+    w: &'a mut W,
+    empty: bool,
+}
+
+impl<'a, W: Write> Object<'a, W> {
+    fn begin(w: &'a mut W) -> Result<Self, fmt::Error> {
+        w.write_char('{')?;
+        Ok(Object { w, empty: true })
+    }
+
+    fn field<V: JsonValue>(&mut self, name: &str, value: V) -> fmt::Result {
+        if !self.empty {
+            self.w.write_char(',')?;
+        }
+        self.empty = false;
+        name.write_json(self.w)?;
+        self.w.write_char(':')?;
+        value.write_json(self.w)
+    }
+
+    fn end(self) -> fmt::Result {
+        self.w.write_char('}')
+    }
+}
+
+fn write_result<W: Write>(w: &mut W, result: &ParseResult) -> fmt::Result {
+    let mut obj = Object::begin(w)?;
+    match result {
+        #[cfg(feature = "gga")]
+        ParseResult::GGA(gga) => {
+            obj.field("type", "GGA")?;
+            obj.field("fix_time", gga.fix_time)?;
+            obj.field("fix_type", &gga.fix_type)?;
+            obj.field("latitude", gga.latitude)?;
+            obj.field("longitude", gga.longitude)?;
+            obj.field("fix_satellites", gga.fix_satellites)?;
+            obj.field("hdop", gga.hdop)?;
+            obj.field("altitude", gga.altitude)?;
+            obj.field("geoid_height", gga.geoid_height)?;
+            obj.field("age_of_dgps", gga.age_of_dgps)?;
+            obj.field("dgps_station_id", gga.dgps_station_id)?;
+        }
+        #[cfg(feature = "rmc")]
+        ParseResult::RMC(rmc) => {
+            obj.field("type", "RMC")?;
+            obj.field("fix_time", rmc.fix_time)?;
+            obj.field("fix_date", rmc.fix_date)?;
+            obj.field("status_of_fix", &rmc.status_of_fix)?;
+            obj.field("lat", rmc.lat)?;
+            obj.field("lon", rmc.lon)?;
+            obj.field("speed_over_ground", rmc.speed_over_ground)?;
+            obj.field("true_course", rmc.true_course)?;
+            obj.field("magnetic_variation", rmc.magnetic_variation)?;
+            obj.field("nav_status", rmc.nav_status)?;
+        }
+        #[cfg(feature = "gsv")]
+        ParseResult::GSV(gsv) => {
+            obj.field("type", "GSV")?;
+            obj.field("gnss_type", &gsv.gnss_type)?;
+            obj.field("number_of_sentences", gsv.number_of_sentences)?;
+            obj.field("sentence_num", gsv.sentence_num)?;
+            obj.field("sats_in_view", gsv.sats_in_view)?;
+            obj.field("sats_info", &gsv.sats_info[..])?;
+            obj.field("signal_id", gsv.signal_id)?;
+        }
+        #[cfg(feature = "gsa")]
+        ParseResult::GSA(gsa) => {
+            obj.field("type", "GSA")?;
+            obj.field("gnss_type", &gsa.gnss_type)?;
+            obj.field("mode1", &gsa.mode1)?;
+            obj.field("mode2", &gsa.mode2)?;
+            obj.field("fix_sats_prn", &gsa.fix_sats_prn[..])?;
+            obj.field("pdop", gsa.pdop)?;
+            obj.field("hdop", gsa.hdop)?;
+            obj.field("vdop", gsa.vdop)?;
+        }
+        #[cfg(feature = "vtg")]
+        ParseResult::VTG(vtg) => {
+            obj.field("type", "VTG")?;
+            obj.field("true_course", vtg.true_course)?;
+            obj.field("magnetic_course", vtg.magnetic_course)?;
+            obj.field("speed_over_ground", vtg.speed_over_ground)?;
+        }
+        #[cfg(feature = "gll")]
+        ParseResult::GLL(gll) => {
+            obj.field("type", "GLL")?;
+            obj.field("latitude", gll.latitude)?;
+            obj.field("longitude", gll.longitude)?;
+            obj.field("fix_time", gll.fix_time)?;
+            obj.field("mode", gll.mode)?;
+        }
+        #[cfg(feature = "vdr")]
+        ParseResult::VDR(vdr) => {
+            obj.field("type", "VDR")?;
+            obj.field("direction_true", vdr.direction_true)?;
+            obj.field("direction_magnetic", vdr.direction_magnetic)?;
+            obj.field("drift_knots", vdr.drift_knots)?;
+        }
+        #[cfg(feature = "hdm")]
+        ParseResult::HDM(hdm) => {
+            obj.field("type", "HDM")?;
+            obj.field("heading", hdm.heading)?;
+        }
+        #[cfg(feature = "ztg")]
+        ParseResult::ZTG(ztg) => {
+            obj.field("type", "ZTG")?;
+            obj.field("utc", ztg.utc)?;
+            obj.field("time_to_go", ztg.time_to_go)?;
+            obj.field("waypoint_id", &ztg.waypoint_id)?;
+        }
+        #[cfg(feature = "gns")]
+        ParseResult::GNS(gns) => {
+            obj.field("type", "GNS")?;
+            obj.field("fix_time", gns.fix_time)?;
+            obj.field("latitude", gns.latitude)?;
+            obj.field("longitude", gns.longitude)?;
+            obj.field("mode_indicator", &gns.mode_indicator)?;
+            obj.field("fix_satellites", gns.fix_satellites)?;
+            obj.field("hdop", gns.hdop)?;
+            obj.field("altitude", gns.altitude)?;
+            obj.field("geoid_height", gns.geoid_height)?;
+            obj.field("age_of_differential", gns.age_of_differential)?;
+            obj.field("differential_station_id", gns.differential_station_id)?;
+            obj.field("nav_status", gns.nav_status)?;
+        }
+        #[cfg(feature = "rma")]
+        ParseResult::RMA(rma) => {
+            obj.field("type", "RMA")?;
+            obj.field("data_valid", rma.data_valid)?;
+            obj.field("latitude", rma.latitude)?;
+            obj.field("longitude", rma.longitude)?;
+            obj.field("time_difference_a", rma.time_difference_a)?;
+            obj.field("time_difference_b", rma.time_difference_b)?;
+            obj.field("speed_over_ground", rma.speed_over_ground)?;
+            obj.field("true_course", rma.true_course)?;
+            obj.field("magnetic_variation", rma.magnetic_variation)?;
+        }
+        #[cfg(feature = "mtw")]
+        ParseResult::MTW(mtw) => {
+            obj.field("type", "MTW")?;
+            obj.field("temperature", mtw.temperature)?;
+            obj.field("unit", mtw.unit)?;
+        }
+        #[cfg(feature = "mta")]
+        ParseResult::MTA(mta) => {
+            obj.field("type", "MTA")?;
+            obj.field("temperature", mta.temperature)?;
+            obj.field("unit", mta.unit)?;
+        }
+        #[cfg(feature = "tll")]
+        ParseResult::TLL(tll) => {
+            obj.field("type", "TLL")?;
+            obj.field("target_number", tll.target_number)?;
+            obj.field("latitude", tll.latitude)?;
+            obj.field("longitude", tll.longitude)?;
+            obj.field("name", &tll.name)?;
+            obj.field("time", tll.time)?;
+            obj.field("status", tll.status)?;
+            obj.field("reference_target", tll.reference_target)?;
+        }
+        #[cfg(feature = "gst")]
+        ParseResult::GST(gst) => {
+            obj.field("type", "GST")?;
+            obj.field("fix_time", gst.fix_time)?;
+            obj.field("rms_sd", gst.rms_sd)?;
+            obj.field("ellipse_semi_major_sd", gst.ellipse_semi_major_sd)?;
+            obj.field("ellipse_semi_minor_sd", gst.ellipse_semi_minor_sd)?;
+            obj.field("ellipse_orientation", gst.ellipse_orientation)?;
+            obj.field("lat_sd", gst.lat_sd)?;
+            obj.field("lon_sd", gst.lon_sd)?;
+            obj.field("alt_sd", gst.alt_sd)?;
+        }
+        #[cfg(feature = "ggk")]
+        ParseResult::GGK(ggk) => {
+            obj.field("type", "GGK")?;
+            obj.field("fix_time", ggk.fix_time)?;
+            obj.field("fix_date", ggk.fix_date)?;
+            obj.field("latitude", ggk.latitude)?;
+            obj.field("longitude", ggk.longitude)?;
+            obj.field("quality", ggk.quality)?;
+            obj.field("fix_satellites", ggk.fix_satellites)?;
+            obj.field("dop", ggk.dop)?;
+            obj.field("ellipsoidal_height", ggk.ellipsoidal_height)?;
+        }
+        ParseResult::Unsupported { talker, sentence } => {
+            obj.field("type", "Unsupported")?;
+            obj.field("talker", talker)?;
+            obj.field("sentence", sentence)?;
+        }
+    }
+    obj.end()
+}
+
+#[cfg(all(test, feature = "all-sentences"))]
+mod tests {
+    use super::*;
+    use crate::export::parsed_track;
+    use crate::parse;
+
+    #[test]
+    fn test_gga_to_json() {
+        let gga = parse(b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76")
+            .unwrap();
+        assert_eq!(
+            to_json(&gga),
+            r#"{"type":"GGA","fix_time":"09:27:50","fix_type":"Gps","latitude":53.361336666666666,"longitude":-6.50562,"fix_satellites":8,"hdop":1.03,"altitude":61.7,"geoid_height":55.2,"age_of_dgps":null,"dgps_station_id":null}"#
+        );
+
+        let gga = parse(b"$GPGGA,,,,,,0,,,,M,,M,,*66").unwrap();
+        assert_eq!(
+            to_json(&gga),
+            r#"{"type":"GGA","fix_time":null,"fix_type":"Invalid","latitude":null,"longitude":null,"fix_satellites":null,"hdop":null,"altitude":null,"geoid_height":null,"age_of_dgps":null,"dgps_station_id":null}"#
+        );
+    }
+
+    #[test]
+    fn test_unsupported_to_json() {
+        let aam = parse(b"$GPAAM,A,A,0.10,N,WPTNME*32").unwrap();
+        assert_eq!(
+            to_json(&aam),
+            r#"{"type":"Unsupported","talker":"Gps","sentence":"AAM"}"#
+        );
+    }
+
+    #[test]
+    fn test_talker_with_data_to_json() {
+        let txt = parse(b"$XQTXT,01,01,01,TEXT*4D").unwrap();
+        assert_eq!(
+            to_json(&txt),
+            r#"{"type":"Unsupported","talker":{"Other":[88,81]},"sentence":"TXT"}"#
+        );
+        let mut out = String::new();
+        TalkerId::Ais('A', 'I').write_json(&mut out).unwrap();
+        assert_eq!(out, r#"{"Ais":["A","I"]}"#);
+        out.clear();
+        GgkQuality::Other(9).write_json(&mut out).unwrap();
+        assert_eq!(out, r#"{"Other":9}"#);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_to_json_matches_serde() {
+        for line in &[
+            &b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76"[..],
+            b"$GPGSV,3,1,11,10,63,137,17,07,61,098,15,05,59,290,20,08,54,157,30*70",
+            b"$GPRMC,092750.000,A,5321.6802,N,00630.3372,W,0.02,31.66,280511,,,A*43",
+            b"$GPAAM,A,A,0.10,N,WPTNME*32",
+            b"$XQTXT,01,01,01,TEXT*4D",
+        ] {
+            let result = parse(line).unwrap();
+            let ours: serde_json::Value = serde_json::from_str(&to_json(&result)).unwrap();
+            // through string, `to_value` widens `f32` fields
+            let serde: serde_json::Value =
+                serde_json::from_str(&serde_json::to_string(&result).unwrap()).unwrap();
+            assert_eq!(ours, serde);
+        }
+    }
+
+    #[test]
+    fn test_to_json_valid() {
+        for line in &[
+            &b"$GPGSV,3,1,11,10,63,137,17,07,61,098,15,05,59,290,20,08,54,157,30*70"[..],
+            b"$GPGSA,A,3,10,07,05,02,29,04,08,13,,,,,1.72,1.03,1.38*0A",
+            b"$GPRMC,092750.000,A,5321.6802,N,00630.3372,W,0.02,31.66,280511,,,A*43",
+            b"$GPAAM,A,A,0.10,N,WPTNME*32",
+        ] {
+            let json = to_json(&parse(line).unwrap());
+            let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+            assert!(value["type"].is_string(), "{}", json);
+        }
+    }
+
+    #[test]
+    fn test_track_to_geojson() {
+        let json = track_to_geojson(&parsed_track());
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["type"], "Feature");
+        assert_eq!(value["geometry"]["type"], "LineString");
+        let coords = value["geometry"]["coordinates"].as_array().unwrap();
+        // GGA and RMC of one fix are separate points
+        assert_eq!(coords.len(), 4);
+        assert_eq!(coords[0][0], -6.50562);
+        assert_eq!(coords[0][1], 53.361336666666666);
+
+        let value: serde_json::Value = serde_json::from_str(&to_geojson_linestring(&[])).unwrap();
+        assert!(value["geometry"]["coordinates"]
+            .as_array()
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_json_escape() {
+        let mut out = String::new();
+        "a\"b\\c\n\u{1}".write_json(&mut out).unwrap();
+        assert_eq!(out, r#""a\"b\\c\n\u0001""#);
+        let value: String = serde_json::from_str(&out).unwrap();
+        assert_eq!(value, "a\"b\\c\n\u{1}");
+    }
+}
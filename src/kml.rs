@@ -0,0 +1,111 @@
+//! KML track export, for Google Earth and similar tools
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::{self, Write};
+
+use crate::export::write_string;
+use crate::ParseResult;
+
+/// KML `Placemark` with `LineString` through points given as
+/// `(latitude, longitude, altitude)`, degrees and meters above
+/// mean sea level. Note that KML itself stores longitude first.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::to_kml_linestring;
+///
+/// assert_eq!(
+///     to_kml_linestring(&[(53.5, -6.5, 61.5), (53.5, -6.25, 62.)]),
+///     "<Placemark><LineString><altitudeMode>absolute</altitudeMode>\
+///      <coordinates>-6.5,53.5,61.5 -6.25,53.5,62</coordinates></LineString></Placemark>"
+/// );
+/// ```
+pub fn to_kml_linestring(fixes: &[(f64, f64, f32)]) -> String {
+    let fixes: Vec<(f64, f64, Option<f32>)> = fixes
+        .iter()
+        .map(|&(lat, lon, alt)| (lat, lon, Some(alt)))
+        .collect();
+    write_string(|out| write_kml_linestring(out, &fixes))
+}
+
+/// KML `Placemark` with `LineString` through positions reported by GGA
+/// sentences, results without position are skipped. If altitude of any
+/// fix is unknown, line is drawn with `clampToGround` altitude mode
+/// and without altitudes, instead of `absolute` one.
+pub fn track_to_kml(results: &[ParseResult]) -> String {
+    let fixes: Vec<(f64, f64, Option<f32>)> = results.iter().filter_map(gga_position).collect();
+    write_string(|out| write_kml_linestring(out, &fixes))
+}
+
+fn gga_position(result: &ParseResult) -> Option<(f64, f64, Option<f32>)> {
+    match result {
+        #[cfg(feature = "gga")]
+        ParseResult::GGA(gga) => Some((gga.latitude?, gga.longitude?, gga.altitude)),
+        _ => None,
+    }
+}
+
+fn write_kml_linestring<W: Write>(w: &mut W, fixes: &[(f64, f64, Option<f32>)]) -> fmt::Result {
+    let absolute = fixes.iter().all(|&(_, _, alt)| alt.is_some());
+    let mode = if absolute {
+        "absolute"
+    } else {
+        "clampToGround"
+    };
+    write!(
+        w,
+        "<Placemark><LineString><altitudeMode>{}</altitudeMode><coordinates>",
+        mode
+    )?;
+    for (i, &(lat, lon, alt)) in fixes.iter().enumerate() {
+        if i > 0 {
+            w.write_char(' ')?;
+        }
+        write!(w, "{},{}", lon, lat)?;
+        if let (true, Some(alt)) = (absolute, alt) {
+            write!(w, ",{}", alt)?;
+        }
+    }
+    w.write_str("</coordinates></LineString></Placemark>")
+}
+
+#[cfg(all(test, feature = "gga", feature = "rmc"))]
+mod tests {
+    use super::*;
+    use crate::export::parsed_track;
+
+    #[test]
+    fn test_track_to_kml() {
+        let results = parsed_track();
+        let coords = |kml: &str| -> Vec<String> {
+            kml.split("<coordinates>")
+                .nth(1)
+                .and_then(|s| s.split("</coordinates>").next())
+                .unwrap()
+                .split(' ')
+                .map(String::from)
+                .collect()
+        };
+        let kml = track_to_kml(&results[..4]);
+        assert!(kml.contains("<altitudeMode>absolute</altitudeMode>"));
+        assert_eq!(coords(&kml), ["-6.50562,53.361336666666666,61.7"]);
+
+        // second fix has no altitude, it isn't drawn at sea level
+        let kml = track_to_kml(&results);
+        assert!(kml.contains("<altitudeMode>clampToGround</altitudeMode>"));
+        assert_eq!(
+            coords(&kml),
+            [
+                "-6.50562,53.361336666666666",
+                "-6.5056183333333335,53.361336666666666"
+            ]
+        );
+
+        assert_eq!(
+            to_kml_linestring(&[]),
+            "<Placemark><LineString><altitudeMode>absolute</altitudeMode>\
+             <coordinates></coordinates></LineString></Placemark>"
+        );
+    }
+}
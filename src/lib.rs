@@ -20,20 +20,85 @@
 // limitations under the License.
 //
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(not(feature = "alloc"))]
+compile_error!("nmea requires \"alloc\" feature (enabled by \"std\")");
+
+extern crate alloc;
+
+#[cfg(feature = "tokio")]
+mod codec;
+mod coordinate;
+mod csv;
+mod dedup;
+mod dispatcher;
+#[cfg(feature = "std")]
+mod distance;
+mod encode;
+mod error;
+mod export;
+#[cfg(feature = "geo")]
+mod geo;
+mod gpx;
+mod heading;
+mod json;
+mod kml;
+mod observation;
 mod parse;
-
-use std::{
-    collections::{HashMap, HashSet},
-    iter::Iterator,
-    {fmt, mem, str},
+#[cfg(feature = "uom")]
+mod units;
+
+use alloc::{borrow::ToOwned, collections::BTreeMap, format, string::String, vec, vec::Vec};
+use core::{fmt, iter::Iterator, str};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+#[cfg(all(
+    feature = "std",
+    any(feature = "gga", feature = "rmc", feature = "vtg")
+))]
+use std::mem;
+
+#[cfg(feature = "tokio")]
+pub use crate::codec::{NmeaCodec, NmeaStream};
+pub use crate::coordinate::Coordinate;
+pub use crate::csv::fixes_to_csv;
+pub use crate::dedup::DedupFilter;
+pub use crate::dispatcher::NmeaDispatcher;
+#[cfg(feature = "std")]
+pub use crate::distance::{
+    fix_bearing_deg, fix_distance_m, haversine_distance_m, initial_bearing_deg, Position,
+    EARTH_MEAN_RADIUS_M,
 };
-
+pub use crate::encode::{
+    append_checksum, encode_gsv, finalize_sentence, to_nmea_lat, to_nmea_lon, GgaBuilder, ToNmea,
+};
+pub use crate::error::NmeaError;
+pub use crate::gpx::{to_gpx, track_points, TrackPoint};
+pub use crate::heading::{magnetic_heading, normalize_degrees, true_heading};
+pub use crate::json::{to_geojson_linestring, to_json, track_to_geojson};
+pub use crate::kml::{to_kml_linestring, track_to_kml};
+pub use crate::observation::Observation;
+#[cfg(feature = "gga")]
+pub use crate::parse::parse_gga;
+#[cfg(feature = "gsv")]
+pub use crate::parse::parse_gsv;
+#[cfg(feature = "rmc")]
+pub use crate::parse::parse_rmc;
 pub use crate::parse::{
-    parse, GgaData, GllData, GsaData, GsvData, ParseResult, RmcData, RmcStatusOfFix, VtgData,
+    classify, field_names, is_complete_frame, is_valid_nmea, parse, parse_full, parse_lines,
+    parse_nmea_sentence, parse_nmea_sentence_str, parse_proprietary_sentence, parse_str,
+    parse_tag_block, parse_with_options, split_sentences, verify_checksum, GgaData, GgkData,
+    GgkQuality, GllData, GnsData, GsaData, GstData, GsvData, HdmData, MtaData, MtwData,
+    NmeaSentence, OwnedNmeaSentence, ParseOptions, ParseResult, PosSystemIndicator,
+    ProprietarySentence, RmaData, RmcData, RmcStatusOfFix, TagBlock, TemperatureUnit, TllData,
+    VdrData, VtgData, ZtgData, MAX_DGPS_AGE, MAX_DGPS_STATION_ID, MAX_GSA_PRNS,
 };
+#[cfg(feature = "std")]
 use chrono::{NaiveDate, NaiveTime};
 
 /// NMEA parser
+#[cfg(feature = "std")]
 #[derive(Default, Debug)]
 pub struct Nmea {
     pub fix_time: Option<NaiveTime>,
@@ -51,12 +116,16 @@ pub struct Nmea {
     pub geoid_height: Option<f32>,
     pub satellites: Vec<Satellite>,
     pub fix_satellites_prns: Option<Vec<u32>>,
-    satellites_scan: HashMap<GnssType, Vec<Vec<Satellite>>>,
+    #[cfg(any(feature = "gga", feature = "gsv", feature = "rmc", feature = "vtg"))]
+    satellites_scan: GsvAssembler,
+    gsa_scan: GsaAccumulator,
     required_sentences_for_nav: HashSet<SentenceType>,
+    #[cfg(any(feature = "gga", feature = "rmc", feature = "vtg"))]
     last_fix_time: Option<NaiveTime>,
     sentences_for_this_time: HashSet<SentenceType>,
 }
 
+#[cfg(feature = "std")]
 impl<'a> Nmea {
     /// Constructs a new `Nmea`.
     /// This struct parses NMEA sentences, including checksum checks and sentence
@@ -65,20 +134,17 @@ impl<'a> Nmea {
     /// # Examples
     ///
     /// ```
+    /// # #[cfg(feature = "gga")] {
     /// use nmea::Nmea;
     ///
     /// let mut nmea= Nmea::new();
     /// let gga = "$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76";
     /// nmea.parse(gga).unwrap();
     /// println!("{}", nmea);
+    /// # }
     /// ```
     pub fn new() -> Nmea {
-        // TODO: This looks ugly.
-        let mut n = Nmea::default();
-//        n.satellites_scan.insert(GnssType::Galileo, vec![]);
-//        n.satellites_scan.insert(GnssType::Gps, vec![]);
-        n.satellites_scan.insert(GnssType::Glonass, vec![]);
-        n
+        Nmea::default()
     }
 
     /// Constructs a new `Nmea` for navigation purposes.
@@ -86,6 +152,7 @@ impl<'a> Nmea {
     /// # Examples
     ///
     /// ```
+    /// # #[cfg(feature = "gga")] {
     /// use nmea::{Nmea, SentenceType};
     ///
     /// let mut nmea = Nmea::create_for_navigation([SentenceType::RMC, SentenceType::GGA]
@@ -95,6 +162,7 @@ impl<'a> Nmea {
     /// let gga = "$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76";
     /// nmea.parse(gga).unwrap();
     /// println!("{}", nmea);
+    /// # }
     /// ```
     pub fn create_for_navigation(
         required_sentences_for_nav: HashSet<SentenceType>,
@@ -152,6 +220,38 @@ impl<'a> Nmea {
         self.satellites.clone()
     }
 
+    /// Returns date of last fix. None if not available.
+    pub fn fix_date(&self) -> Option<NaiveDate> {
+        self.fix_date
+    }
+
+    /// Returns speed over ground in knots. None if not available.
+    pub fn speed_over_ground(&self) -> Option<f32> {
+        self.speed_over_ground
+    }
+
+    /// Returns course over ground in degrees relative to true north.
+    /// None if not available.
+    pub fn true_course(&self) -> Option<f32> {
+        self.true_course
+    }
+
+    /// Returns the number fix PDOP
+    pub fn pdop(&self) -> Option<f32> {
+        self.pdop
+    }
+
+    /// Returns the number fix VDOP
+    pub fn vdop(&self) -> Option<f32> {
+        self.vdop
+    }
+
+    /// Returns PRNs of satellites used for fix
+    pub fn fix_satellites_prns(&self) -> Option<&[u32]> {
+        self.fix_satellites_prns.as_deref()
+    }
+
+    #[cfg(feature = "gga")]
     fn merge_gga_data(&mut self, gga_data: GgaData) {
         self.fix_time = gga_data.fix_time;
         self.latitude = gga_data.latitude;
@@ -163,36 +263,15 @@ impl<'a> Nmea {
         self.geoid_height = gga_data.geoid_height;
     }
 
-    fn merge_gsv_data(&mut self, data: GsvData) -> Result<(), &'static str> {
-        {
-            let d = self
-                .satellites_scan
-                .get_mut(&data.gnss_type)
-                .ok_or("Invalid GNSS type")?;
-            // Adjust size to this scan
-            d.resize(data.number_of_sentences as usize, vec![]);
-            // Replace data at index with new scan data
-            d.push(
-                data.sats_info
-                    .iter()
-                    .filter(|v| v.is_some())
-                    .map(|v| v.clone().unwrap())
-                    .collect(),
-            );
-            d.swap_remove(data.sentence_num as usize - 1);
-        }
-        self.satellites.clear();
-        for v in self.satellites_scan.values() {
-            for v1 in v {
-                for v2 in v1 {
-                    self.satellites.push(v2.clone());
-                }
-            }
-        }
-
+    #[cfg(feature = "gsv")]
+    fn merge_gsv_data(&mut self, data: GsvData) -> Result<(), NmeaError> {
+        self.satellites_scan.merge(data)?;
+        self.satellites = self.satellites_scan.satellites();
+        self.gsa_scan.mark_used_in_fix(&mut self.satellites);
         Ok(())
     }
 
+    #[cfg(feature = "rmc")]
     fn merge_rmc_data(&mut self, rmc_data: RmcData) {
         self.fix_time = rmc_data.fix_time;
         self.fix_date = rmc_data.fix_date;
@@ -207,18 +286,30 @@ impl<'a> Nmea {
         self.true_course = rmc_data.true_course;
     }
 
-    fn merge_gsa_data(&mut self, gsa: GsaData) {
-        self.fix_satellites_prns = Some(gsa.fix_sats_prn);
-        self.hdop = gsa.hdop;
-        self.vdop = gsa.vdop;
-        self.pdop = gsa.pdop;
+    /// GSA data is already collected by `gsa_scan`,
+    /// so PRNs and DOPs are taken from whole cycle
+    #[cfg(feature = "gsa")]
+    fn merge_gsa_data(&mut self) {
+        self.fix_satellites_prns = Some(
+            self.gsa_scan
+                .fix_sats_prn()
+                .into_iter()
+                .map(|(prn, _)| prn)
+                .collect(),
+        );
+        self.hdop = self.gsa_scan.hdop();
+        self.vdop = self.gsa_scan.vdop();
+        self.pdop = self.gsa_scan.pdop();
+        self.gsa_scan.mark_used_in_fix(&mut self.satellites);
     }
 
+    #[cfg(feature = "vtg")]
     fn merge_vtg_data(&mut self, vtg: VtgData) {
         self.speed_over_ground = vtg.speed_over_ground;
         self.true_course = vtg.true_course;
     }
 
+    #[cfg(feature = "gll")]
     fn merge_gll_data(&mut self, gll: GllData) {
         self.latitude = Some(gll.latitude);
         self.longitude = Some(gll.longitude);
@@ -227,75 +318,130 @@ impl<'a> Nmea {
 
     /// Parse any NMEA sentence and stores the result. The type of sentence
     /// is returnd if implemented and valid.
-    pub fn parse(&mut self, s: &'a str) -> Result<SentenceType, String> {
-        match parse(s.as_bytes())? {
+    pub fn parse(&mut self, s: &'a str) -> Result<SentenceType, NmeaError> {
+        self.update(s.as_bytes())
+    }
+
+    /// Same as `parse`, but for raw bytes of one sentence, as they come from receiver.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "rmc")] {
+    /// use nmea::{Nmea, SentenceType};
+    ///
+    /// let mut nmea = Nmea::new();
+    /// let rmc = b"$GPRMC,225446.33,A,4916.45,N,12311.12,W,000.5,054.7,191194,020.3,E,A*2B";
+    /// assert_eq!(nmea.update(rmc).unwrap(), SentenceType::RMC);
+    /// assert_eq!(nmea.speed_over_ground(), Some(0.5));
+    /// # }
+    /// ```
+    pub fn update(&mut self, sentence: &[u8]) -> Result<SentenceType, NmeaError> {
+        let result = parse(sentence)?;
+        self.gsa_scan.update(&result);
+        match result {
+            #[cfg(feature = "vtg")]
             ParseResult::VTG(vtg) => {
                 self.merge_vtg_data(vtg);
                 Ok(SentenceType::VTG)
             }
+            #[cfg(feature = "gga")]
             ParseResult::GGA(gga) => {
                 self.merge_gga_data(gga);
                 Ok(SentenceType::GGA)
             }
+            #[cfg(feature = "gsv")]
             ParseResult::GSV(gsv) => {
                 self.merge_gsv_data(gsv)?;
                 Ok(SentenceType::GSV)
             }
+            #[cfg(feature = "rmc")]
             ParseResult::RMC(rmc) => {
                 self.merge_rmc_data(rmc);
                 Ok(SentenceType::RMC)
             }
-            ParseResult::GSA(gsa) => {
-                self.merge_gsa_data(gsa);
+            #[cfg(feature = "gsa")]
+            ParseResult::GSA(_) => {
+                self.merge_gsa_data();
                 Ok(SentenceType::GSA)
             }
+            #[cfg(feature = "gll")]
             ParseResult::GLL(gll) => {
                 self.merge_gll_data(gll);
                 Ok(SentenceType::GLL)
             }
-            ParseResult::Unsupported(msg_id) => Err(format!(
-                "Unknown or implemented sentence type: {:?}",
-                msg_id
-            )),
+            #[cfg(feature = "vdr")]
+            ParseResult::VDR(_) => Ok(SentenceType::VDR),
+            #[cfg(feature = "hdm")]
+            ParseResult::HDM(_) => Ok(SentenceType::HDM),
+            #[cfg(feature = "ztg")]
+            ParseResult::ZTG(_) => Ok(SentenceType::ZTG),
+            #[cfg(feature = "gns")]
+            ParseResult::GNS(_) => Ok(SentenceType::GNS),
+            #[cfg(feature = "rma")]
+            ParseResult::RMA(_) => Ok(SentenceType::RMA),
+            #[cfg(feature = "mtw")]
+            ParseResult::MTW(_) => Ok(SentenceType::MTW),
+            #[cfg(feature = "mta")]
+            ParseResult::MTA(_) => Ok(SentenceType::MTA),
+            #[cfg(feature = "tll")]
+            ParseResult::TLL(_) => Ok(SentenceType::TLL),
+            #[cfg(feature = "gst")]
+            ParseResult::GST(_) => Ok(SentenceType::GST),
+            #[cfg(feature = "ggk")]
+            ParseResult::GGK(_) => Ok(SentenceType::GGK),
+            ParseResult::Unsupported { sentence, .. } => Err(NmeaError::Unsupported(sentence)),
         }
     }
 
+    #[cfg(any(feature = "gga", feature = "rmc", feature = "vtg"))]
     fn new_tick(&mut self) {
-        let old = mem::replace(self, Self::default());
+        let old = mem::take(self);
         self.satellites_scan = old.satellites_scan;
+        self.gsa_scan = old.gsa_scan;
         self.satellites = old.satellites;
         self.required_sentences_for_nav = old.required_sentences_for_nav;
         self.last_fix_time = old.last_fix_time;
     }
 
+    #[cfg(any(feature = "gga", feature = "rmc", feature = "vtg"))]
     fn clear_position_info(&mut self) {
         self.last_fix_time = None;
         self.new_tick();
     }
 
-    pub fn parse_for_fix(&mut self, xs: &[u8]) -> Result<FixType, String> {
-        match parse(xs)? {
-            ParseResult::GSA(gsa) => {
-                self.merge_gsa_data(gsa);
+    // only GGA, RMC and VTG arms reach the fix check after match
+    #[cfg_attr(
+        not(any(feature = "gga", feature = "rmc", feature = "vtg")),
+        allow(unreachable_code)
+    )]
+    pub fn parse_for_fix(&mut self, xs: &[u8]) -> Result<FixType, NmeaError> {
+        let result = parse(xs)?;
+        self.gsa_scan.update(&result);
+        match result {
+            #[cfg(feature = "gsa")]
+            ParseResult::GSA(_) => {
+                self.merge_gsa_data();
                 return Ok(FixType::Invalid);
             }
+            #[cfg(feature = "gsv")]
             ParseResult::GSV(gsv_data) => {
                 self.merge_gsv_data(gsv_data)?;
                 return Ok(FixType::Invalid);
             }
-            ParseResult::VTG(vtg) => {
-                //have no time field, so only if user explicity mention it
-                if self.required_sentences_for_nav.contains(&SentenceType::VTG) {
-                    if vtg.true_course.is_none() || vtg.speed_over_ground.is_none() {
-                        self.clear_position_info();
-                        return Ok(FixType::Invalid);
-                    }
-                    self.merge_vtg_data(vtg);
-                    self.sentences_for_this_time.insert(SentenceType::VTG);
-                } else {
+            //have no time field, so only if user explicity mention it
+            #[cfg(feature = "vtg")]
+            ParseResult::VTG(vtg)
+                if self.required_sentences_for_nav.contains(&SentenceType::VTG) =>
+            {
+                if vtg.true_course.is_none() || vtg.speed_over_ground.is_none() {
+                    self.clear_position_info();
                     return Ok(FixType::Invalid);
                 }
+                self.merge_vtg_data(vtg);
+                self.sentences_for_this_time.insert(SentenceType::VTG);
             }
+            #[cfg(feature = "rmc")]
             ParseResult::RMC(rmc_data) => {
                 match rmc_data.status_of_fix {
                     Some(RmcStatusOfFix::Invalid) | None => {
@@ -320,6 +466,7 @@ impl<'a> Nmea {
                 self.merge_rmc_data(rmc_data);
                 self.sentences_for_this_time.insert(SentenceType::RMC);
             }
+            #[cfg(feature = "gga")]
             ParseResult::GGA(gga_data) => {
                 match gga_data.fix_type {
                     Some(FixType::Invalid) | None => {
@@ -344,11 +491,12 @@ impl<'a> Nmea {
                 self.merge_gga_data(gga_data);
                 self.sentences_for_this_time.insert(SentenceType::GGA);
             }
+            #[cfg(feature = "gll")]
             ParseResult::GLL(gll_data) => {
                 self.merge_gll_data(gll_data);
                 return Ok(FixType::Invalid);
             }
-            ParseResult::Unsupported(_) => {
+            _ => {
                 return Ok(FixType::Invalid);
             }
         }
@@ -366,6 +514,328 @@ impl<'a> Nmea {
     }
 }
 
+/// Collects satellites from GSV sentences of all constellations.
+///
+/// GSV data for one constellation is split into several sentences,
+/// the assembler keeps the last received sentence for each position
+/// in the cycle and validates that sentences are consistent with each other.
+/// Cycles of different NMEA 4.11 signals of one constellation are collected
+/// separately.
+#[derive(Default, Debug, Clone)]
+pub struct GsvAssembler {
+    /// Report satellite tracked on several signals (frequencies) once,
+    /// with the best SNR, in `satellites`
+    pub dedup_by_prn: bool,
+    scans: BTreeMap<(GnssType, Option<u8>), GsvScan>,
+}
+
+#[derive(Default, Debug, Clone)]
+struct GsvScan {
+    sats_in_view: u16,
+    sentences: Vec<Vec<Satellite>>,
+}
+
+impl GsvAssembler {
+    pub fn new() -> GsvAssembler {
+        GsvAssembler::default()
+    }
+
+    /// Merge one GSV sentence into cycle of its constellation.
+    ///
+    /// Change of number of sentences, or new value of satellites in view
+    /// in the first sentence, starts new cycle. Sentence is rejected
+    /// if its number is out of range, it reports other number of satellites
+    /// in view than the rest of the cycle, or cycle would contain more
+    /// satellites than in view.
+    pub fn merge(&mut self, data: GsvData) -> Result<(), NmeaError> {
+        if data.sentence_num == 0 || data.sentence_num > data.number_of_sentences {
+            return Err(NmeaError::InvalidSequence(
+                "GSV sentence number out of range",
+            ));
+        }
+        let scan = self
+            .scans
+            .entry((data.gnss_type.clone(), data.signal_id))
+            .or_default();
+        let idx = usize::from(data.sentence_num - 1);
+        let new_cycle = scan.sentences.len() != usize::from(data.number_of_sentences)
+            || (data.sentence_num == 1 && scan.sats_in_view != data.sats_in_view);
+        if !new_cycle && scan.sats_in_view != data.sats_in_view {
+            return Err(NmeaError::InvalidSequence(
+                "GSV satellites in view mismatch within cycle",
+            ));
+        }
+        let sats: Vec<Satellite> = data.sats_info.iter().flatten().cloned().collect();
+        let collected: usize = if new_cycle {
+            0
+        } else {
+            scan.sentences
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != idx)
+                .map(|(_, v)| v.len())
+                .sum()
+        };
+        if collected + sats.len() > usize::from(data.sats_in_view) {
+            return Err(NmeaError::InvalidSequence(
+                "GSV cycle contains more satellites than in view",
+            ));
+        }
+        if new_cycle {
+            scan.sats_in_view = data.sats_in_view;
+            scan.sentences.clear();
+            scan.sentences
+                .resize(usize::from(data.number_of_sentences), vec![]);
+        }
+        scan.sentences[idx] = sats;
+        Ok(())
+    }
+
+    /// Satellites of all constellations collected so far
+    pub fn satellites(&self) -> Vec<Satellite> {
+        let sats = self
+            .scans
+            .values()
+            .flat_map(|scan| scan.sentences.iter().flatten().cloned());
+        if !self.dedup_by_prn {
+            return sats.collect();
+        }
+        let mut best = BTreeMap::<(GnssType, u32), Satellite>::new();
+        for sat in sats {
+            let key = (sat.gnss_type(), sat.prn());
+            // None is less than any SNR
+            match best.get(&key) {
+                Some(prev) if prev.snr() >= sat.snr() => {}
+                _ => {
+                    best.insert(key, sat);
+                }
+            }
+        }
+        best.into_values().collect()
+    }
+}
+
+/// Merges GSA sentences of one reporting cycle into one fix.
+///
+/// Some receivers (Skytraq for example) report one combined fix with
+/// several GSA sentences, one per constellation. Cycle is delimited by
+/// RMC or GGA, so first GSA after RMC/GGA starts new cycle.
+#[derive(Default, Debug, Clone)]
+pub struct GsaAccumulator {
+    sentences: Vec<GsaData>,
+    #[cfg(any(feature = "gga", feature = "gsa", feature = "rmc"))]
+    cycle_closed: bool,
+}
+
+impl GsaAccumulator {
+    pub fn new() -> GsaAccumulator {
+        GsaAccumulator::default()
+    }
+
+    /// Feed parsed sentence, everything except GSA, RMC and GGA is ignored
+    pub fn update(&mut self, result: &ParseResult) {
+        match result {
+            #[cfg(feature = "gsa")]
+            ParseResult::GSA(gsa) => {
+                if self.cycle_closed {
+                    self.sentences.clear();
+                    self.cycle_closed = false;
+                }
+                self.sentences.push(gsa.clone());
+            }
+            #[cfg(feature = "rmc")]
+            ParseResult::RMC(_) => {
+                self.cycle_closed = !self.sentences.is_empty();
+            }
+            #[cfg(feature = "gga")]
+            ParseResult::GGA(_) => {
+                self.cycle_closed = !self.sentences.is_empty();
+            }
+            _ => {}
+        }
+    }
+
+    /// GSA sentences of current cycle
+    pub fn sentences(&self) -> &[GsaData] {
+        &self.sentences
+    }
+
+    /// PRNs of satellites used in fix with constellation
+    /// they belong to (if known from talker or system id).
+    ///
+    /// Several sentences with the same talker (like two GNGSA in one cycle)
+    /// are combined, each PRN reported only once.
+    pub fn fix_sats_prn(&self) -> Vec<(u32, Option<GnssType>)> {
+        let mut ret: Vec<(u32, Option<GnssType>)> = Vec::new();
+        for gsa in &self.sentences {
+            for prn in &gsa.fix_sats_prn {
+                let sat = (*prn, gsa.gnss_type.clone());
+                if !ret.contains(&sat) {
+                    ret.push(sat);
+                }
+            }
+        }
+        ret
+    }
+
+    /// Number of satellites used in fix by all GSA sentences of cycle,
+    /// satellite reported by several sentences is counted once
+    pub fn satellites_used(&self) -> usize {
+        self.fix_sats_prn().len()
+    }
+
+    /// Set `used_in_fix` of satellites from GSV: satellite is used
+    /// if its PRN is in the most recent GSA of cycle for its constellation
+    pub fn mark_used_in_fix(&self, sats: &mut [Satellite]) {
+        for sat in sats {
+            sat.used_in_fix = self
+                .sentences
+                .iter()
+                .rev()
+                .find(|gsa| gsa_constellation(gsa).as_ref() == Some(&sat.gnss_type))
+                .is_some_and(|gsa| gsa.fix_sats_prn.contains(&sat.prn));
+        }
+    }
+
+    /// Best (lowest) PDOP reported in cycle
+    pub fn pdop(&self) -> Option<f32> {
+        best_dop(self.sentences.iter().map(|v| v.pdop))
+    }
+
+    /// Best (lowest) HDOP reported in cycle
+    pub fn hdop(&self) -> Option<f32> {
+        best_dop(self.sentences.iter().map(|v| v.hdop))
+    }
+
+    /// Best (lowest) VDOP reported in cycle
+    pub fn vdop(&self) -> Option<f32> {
+        best_dop(self.sentences.iter().map(|v| v.vdop))
+    }
+}
+
+/// Merges GSA sentences of one epoch split by constellation.
+///
+/// Unlike `GsaAccumulator` doesn't need RMC/GGA to delimit cycle:
+/// constellation of each sentence is taken from talker or system id,
+/// or guessed from PRN range of its satellites, and GSA of constellation
+/// already present in cycle starts the new one.
+#[derive(Default, Debug, Clone)]
+pub struct GsaCycle {
+    sentences: Vec<GsaData>,
+}
+
+impl GsaCycle {
+    pub fn new() -> GsaCycle {
+        GsaCycle::default()
+    }
+
+    /// Merge one GSA sentence into current cycle
+    pub fn merge(&mut self, data: GsaData) {
+        let gnss = gsa_constellation(&data);
+        if self
+            .sentences
+            .iter()
+            .any(|gsa| gsa_constellation(gsa) == gnss)
+        {
+            self.sentences.clear();
+        }
+        self.sentences.push(data);
+    }
+
+    /// GSA sentences of current cycle
+    pub fn sentences(&self) -> &[GsaData] {
+        &self.sentences
+    }
+
+    /// PRNs of satellites used in fix with their constellation,
+    /// each PRN reported only once
+    pub fn fix_sats_prn(&self) -> Vec<(u32, Option<GnssType>)> {
+        let mut ret: Vec<(u32, Option<GnssType>)> = Vec::new();
+        for sat in self.sentences.iter().flat_map(GsaData::prns_with_gnss) {
+            if !ret.contains(&sat) {
+                ret.push(sat);
+            }
+        }
+        ret
+    }
+
+    /// Number of satellites used in fix by all constellations of cycle
+    pub fn satellites_used(&self) -> usize {
+        self.fix_sats_prn().len()
+    }
+
+    /// Best (lowest) PDOP reported in cycle
+    pub fn pdop(&self) -> Option<f32> {
+        best_dop(self.sentences.iter().map(|v| v.pdop))
+    }
+
+    /// Best (lowest) HDOP reported in cycle
+    pub fn hdop(&self) -> Option<f32> {
+        best_dop(self.sentences.iter().map(|v| v.hdop))
+    }
+
+    /// Best (lowest) VDOP reported in cycle
+    pub fn vdop(&self) -> Option<f32> {
+        best_dop(self.sentences.iter().map(|v| v.vdop))
+    }
+}
+
+/// Number of satellites used in fix per constellation, for epoch
+/// reported by several GSA sentences.
+///
+/// Constellation of PRN is taken from GSA talker or system id, then from GSV
+/// sentences which report this PRN for only one constellation, and finally
+/// guessed by `prn_to_gnss`. GPS GSA reports SBAS and QZSS satellites too,
+/// so their PRN ranges take precedence over GPS talker or system id.
+/// Satellites of unknown constellation are skipped.
+#[cfg(feature = "std")]
+pub fn satellites_used_by_gnss(gsa: &[GsaData], gsv: &[GsvData]) -> HashMap<GnssType, usize> {
+    let in_view = |prn: u32| -> Option<GnssType> {
+        let mut found = gsv
+            .iter()
+            .flat_map(|data| data.sats_info.iter().flatten())
+            .filter(|sat| sat.prn == prn)
+            .map(|sat| sat.gnss_type.clone());
+        let first = found.next()?;
+        if found.all(|gnss| gnss == first) {
+            Some(first)
+        } else {
+            None
+        }
+    };
+    let mut used = HashSet::new();
+    for data in gsa {
+        for &prn in &data.fix_sats_prn {
+            let gnss = match (&data.gnss_type, prn_to_gnss(prn)) {
+                (Some(GnssType::Gps), Some(gnss @ (GnssType::Sbas | GnssType::Qzss))) => Some(gnss),
+                (gnss, guess) => gnss.clone().or_else(|| in_view(prn)).or(guess),
+            };
+            if let Some(gnss) = gnss {
+                used.insert((gnss, prn));
+            }
+        }
+    }
+    let mut ret = HashMap::new();
+    for (gnss, _) in used {
+        *ret.entry(gnss).or_insert(0) += 1;
+    }
+    ret
+}
+
+fn gsa_constellation(gsa: &GsaData) -> Option<GnssType> {
+    gsa.gnss_type
+        .clone()
+        .or_else(|| gsa.fix_sats_prn.first().and_then(|&prn| prn_to_gnss(prn)))
+}
+
+fn best_dop<I: Iterator<Item = Option<f32>>>(dops: I) -> Option<f32> {
+    dops.flatten().fold(None, |best, v| match best {
+        Some(best) if best <= v => Some(best),
+        _ => Some(v),
+    })
+}
+
+#[cfg(feature = "std")]
 impl fmt::Display for Nmea {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -390,15 +860,35 @@ impl fmt::Display for Nmea {
 
 #[derive(Clone, PartialEq)]
 /// ! A Satellite
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Satellite {
     gnss_type: GnssType,
     prn: u32,
     elevation: Option<f32>,
     azimuth: Option<f32>,
     snr: Option<f32>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    used_in_fix: bool,
 }
 
 impl Satellite {
+    pub fn new(
+        gnss_type: GnssType,
+        prn: u32,
+        elevation: Option<f32>,
+        azimuth: Option<f32>,
+        snr: Option<f32>,
+    ) -> Satellite {
+        Satellite {
+            gnss_type,
+            prn,
+            elevation,
+            azimuth,
+            snr,
+            used_in_fix: false,
+        }
+    }
+
     pub fn gnss_type(&self) -> GnssType {
         self.gnss_type.clone()
     }
@@ -418,6 +908,73 @@ impl Satellite {
     pub fn snr(&self) -> Option<f32> {
         self.snr
     }
+
+    /// Satellite is used for fix according to GSA sentences,
+    /// set by `GsaAccumulator::mark_used_in_fix`
+    pub fn used_in_fix(&self) -> bool {
+        self.used_in_fix
+    }
+
+    /// Satellite number in RINEX notation: constellation letter and
+    /// two digit number, like `G05`, `R12` or `C14`.
+    ///
+    /// NMEA numbers with offset are converted: GLONASS 65-96 to slots 1-32,
+    /// SBAS 33-64 to PRN 120-151 and then like other SBAS PRN to RINEX
+    /// number PRN - 100, QZSS 193-200 to 1-8, BeiDou 201-237 and 401-437
+    /// to 1-37 and Galileo 301-336 to 1-36.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nmea::{GnssType, Satellite};
+    ///
+    /// assert_eq!(Satellite::new(GnssType::Glonass, 76, None, None, None).rinex_id(), "R12");
+    /// assert_eq!(Satellite::new(GnssType::Sbas, 46, None, None, None).rinex_id(), "S33");
+    /// ```
+    pub fn rinex_id(&self) -> String {
+        let (letter, number) = match self.gnss_type {
+            GnssType::Gps => ('G', self.prn),
+            GnssType::Glonass => ('R', remove_prn_offset(self.prn, 65..=96, 64)),
+            GnssType::Galileo => ('E', remove_prn_offset(self.prn, 301..=336, 300)),
+            GnssType::Beidou => (
+                'C',
+                remove_prn_offset(remove_prn_offset(self.prn, 201..=237, 200), 401..=437, 400),
+            ),
+            GnssType::Qzss => ('J', remove_prn_offset(self.prn, 193..=200, 192)),
+            GnssType::Sbas => {
+                let prn = match self.prn {
+                    33..=64 => self.prn + 87,
+                    prn => prn,
+                };
+                ('S', remove_prn_offset(prn, 120..=158, 100))
+            }
+        };
+        format!("{}{:02}", letter, number)
+    }
+
+    /// Direction from receiver to satellite as unit vector `[east, north, up]`
+    /// in local ENU (East, North, Up) frame: azimuth is clockwise from
+    /// true north, elevation is above horizon, so satellite in zenith
+    /// is `[0, 0, 1]` and on horizon to the east is `[1, 0, 0]`.
+    /// `None` if elevation or azimuth is unknown.
+    #[cfg(feature = "std")]
+    pub fn enu_unit_vector(&self) -> Option<[f32; 3]> {
+        let elevation = self.elevation?.to_radians();
+        let azimuth = self.azimuth?.to_radians();
+        Some([
+            elevation.cos() * azimuth.sin(),
+            elevation.cos() * azimuth.cos(),
+            elevation.sin(),
+        ])
+    }
+}
+
+fn remove_prn_offset(prn: u32, range: core::ops::RangeInclusive<u32>, offset: u32) -> u32 {
+    if range.contains(&prn) {
+        prn - offset
+    } else {
+        prn
+    }
 }
 
 impl fmt::Display for Satellite {
@@ -444,15 +1001,50 @@ impl fmt::Debug for Satellite {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "[{:?},{:?},{:?},{:?},{:?}]",
-            self.gnss_type, self.prn, self.elevation, self.azimuth, self.snr
+            "[{:?},{:?},{:?},{:?},{:?},{:?}]",
+            self.gnss_type, self.prn, self.elevation, self.azimuth, self.snr, self.used_in_fix
         )
     }
 }
 
+/// Statistics of signal to noise ratio of satellites, in dB-Hz
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SnrStats {
+    pub min: f32,
+    pub max: f32,
+    pub mean: f32,
+    /// Number of satellites with known SNR
+    pub count: usize,
+}
+
+/// SNR statistics of satellites from GSV sentence or whole GSV cycle,
+/// satellites without SNR (not tracked) are ignored, `None` if there
+/// are no satellites with SNR
+pub fn snr_stats(sats: &[Satellite]) -> Option<SnrStats> {
+    let mut snrs = sats.iter().filter_map(Satellite::snr);
+    let first = snrs.next()?;
+    let mut stats = SnrStats {
+        min: first,
+        max: first,
+        mean: 0.,
+        count: 1,
+    };
+    let mut sum = first;
+    for snr in snrs {
+        stats.min = stats.min.min(snr);
+        stats.max = stats.max.max(snr);
+        stats.count += 1;
+        sum += snr;
+    }
+    stats.mean = sum / stats.count as f32;
+    Some(stats)
+}
+
 macro_rules! define_sentence_type_enum {
-    ($Name:ident { $($Variant:ident),* $(,)* }) => {
+    ($(#[$outer:meta])* $Name:ident { $($Variant:ident),* $(,)* }) => {
+        $(#[$outer])*
         #[derive(PartialEq, Debug, Hash, Eq, Clone)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub enum $Name {
             None,
             $($Variant),*,
@@ -478,160 +1070,354 @@ macro_rules! define_sentence_type_enum {
     }
 }
 
-/// ! NMEA sentence type
-/// ! General: OSD |
-/// ! Autopilot: APA | APB | ASD |
-/// ! Decca: DCN |
-/// ! D-GPS: MSK
-/// ! Echo: DBK | DBS | DBT |
-/// ! Radio: FSI | SFI | TLL
-/// ! Speed: VBW | VHW | VLW |
-/// ! GPS: ALM | GBS | GGA | GNS | GSA | GSV |
-/// ! Course: DPT | HDG | HDM | HDT | HSC | ROT | VDR |
-/// ! Loran-C: GLC | LCD |
-/// ! Machine: RPM |
-/// ! Navigation: RMA | RMB | RMC |
-/// ! Omega: OLN |
-/// ! Position: GLL | DTM
-/// ! Radar: RSD | TLL | TTM |
-/// ! Rudder: RSA |
-/// ! Temperature: MTW |
-/// ! Transit: GXA | RTF |
-/// ! Waypoints and tacks: AAM | BEC | BOD | BWC | BWR | BWW | ROO | RTE |
-/// !                      VTG | WCV | WNC | WPL | XDR | XTE | XTR |
-/// ! Wind: MWV | VPW | VWR |
-/// ! Date and Time: GDT | ZDA | ZFO | ZTG |
-define_sentence_type_enum!(SentenceType {
-    AAM,
-    ABK,
-    ACA,
-    ACK,
-    ACS,
-    AIR,
-    ALM,
-    ALR,
-    APA,
-    APB,
-    ASD,
-    BEC,
-    BOD,
-    BWC,
-    BWR,
-    BWW,
-    CUR,
-    DBK,
-    DBS,
-    DBT,
-    DCN,
-    DPT,
-    DSC,
-    DSE,
-    DSI,
-    DSR,
-    DTM,
-    FSI,
-    GBS,
-    GGA,
-    GLC,
-    GLL,
-    GMP,
-    GNS,
-    GRS,
-    GSA,
-    GST,
-    GSV,
-    GTD,
-    GXA,
-    HDG,
-    HDM,
-    HDT,
-    HMR,
-    HMS,
-    HSC,
-    HTC,
-    HTD,
-    LCD,
-    LRF,
-    LRI,
-    LR1,
-    LR2,
-    LR3,
-    MLA,
-    MSK,
-    MSS,
-    MWD,
-    MTW,
-    MWV,
-    OLN,
-    OSD,
-    ROO,
-    RMA,
-    RMB,
-    RMC,
-    ROT,
-    RPM,
-    RSA,
-    RSD,
-    RTE,
-    SFI,
-    SSD,
-    STN,
-    TLB,
-    TLL,
-    TRF,
-    TTM,
-    TUT,
-    TXT,
-    VBW,
-    VDM,
-    VDO,
-    VDR,
-    VHW,
-    VLW,
-    VPW,
-    VSD,
-    VTG,
-    VWR,
-    WCV,
-    WNC,
-    WPL,
-    XDR,
-    XTE,
-    XTR,
-    ZDA,
-    ZDL,
-    ZFO,
-    ZTG,
-});
+define_sentence_type_enum!(
+    /// ! NMEA sentence type
+    /// ! General: OSD |
+    /// ! Autopilot: APA | APB | ASD |
+    /// ! Decca: DCN |
+    /// ! D-GPS: MSK
+    /// ! Echo: DBK | DBS | DBT |
+    /// ! Radio: FSI | SFI | TLL
+    /// ! Speed: VBW | VHW | VLW |
+    /// ! GPS: ALM | GBS | GGA | GNS | GSA | GSV |
+    /// ! Course: DPT | HDG | HDM | HDT | HSC | ROT | VDR |
+    /// ! Loran-C: GLC | LCD |
+    /// ! Machine: RPM |
+    /// ! Navigation: RMA | RMB | RMC |
+    /// ! Omega: OLN |
+    /// ! Position: GLL | DTM
+    /// ! Radar: RSD | TLL | TTM |
+    /// ! Rudder: RSA |
+    /// ! Temperature: MTA | MTW |
+    /// ! Transit: GXA | RTF |
+    /// ! Waypoints and tacks: AAM | BEC | BOD | BWC | BWR | BWW | ROO | RTE |
+    /// !                      VTG | WCV | WNC | WPL | XDR | XTE | XTR |
+    /// ! Wind: MWV | VPW | VWR |
+    /// ! Date and Time: GDT | ZDA | ZFO | ZTG |
+    SentenceType {
+        AAM,
+        ABK,
+        ACA,
+        ACK,
+        ACS,
+        AIR,
+        ALM,
+        ALR,
+        APA,
+        APB,
+        ASD,
+        BEC,
+        BOD,
+        BWC,
+        BWR,
+        BWW,
+        CUR,
+        DBK,
+        DBS,
+        DBT,
+        DCN,
+        DPT,
+        DSC,
+        DSE,
+        DSI,
+        DSR,
+        DTM,
+        FSI,
+        GBS,
+        GGA,
+        GGK,
+        GLC,
+        GLL,
+        GMP,
+        GNS,
+        GRS,
+        GSA,
+        GST,
+        GSV,
+        GTD,
+        GXA,
+        HDG,
+        HDM,
+        HDT,
+        HMR,
+        HMS,
+        HSC,
+        HTC,
+        HTD,
+        LCD,
+        LRF,
+        LRI,
+        LR1,
+        LR2,
+        LR3,
+        MLA,
+        MSK,
+        MSS,
+        MWD,
+        MTA,
+        MTW,
+        MWV,
+        OLN,
+        OSD,
+        ROO,
+        RMA,
+        RMB,
+        RMC,
+        ROT,
+        RPM,
+        RSA,
+        RSD,
+        RTE,
+        SFI,
+        SSD,
+        STN,
+        TLB,
+        TLL,
+        TRF,
+        TTM,
+        TUT,
+        TXT,
+        VBW,
+        VDM,
+        VDO,
+        VDR,
+        VHW,
+        VLW,
+        VPW,
+        VSD,
+        VTG,
+        VWR,
+        WCV,
+        WNC,
+        WPL,
+        XDR,
+        XTE,
+        XTR,
+        ZDA,
+        ZDL,
+        ZFO,
+        ZTG,
+    }
+);
 
 /// ! Fix type
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FixType {
     Invalid,
     Gps,
     DGps,
+    /// Precise Positioning Service
     Pps,
-    Rtk,
-    FloatRtk,
+    /// Real Time Kinematic with fixed integers
+    RtkFixed,
+    /// Real Time Kinematic with float integers
+    RtkFloat,
+    /// Estimated (dead reckoning)
     Estimated,
+    /// Manual input mode
     Manual,
-    Simulation,
+    Simulator,
 }
 
 /// ! GNSS type
-#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GnssType {
     Galileo,
     Gps,
     Glonass,
+    Beidou,
+    /// Japanese regional system, used as GPS augmentation
+    Qzss,
+    /// Satellite based augmentation: WAAS, EGNOS, MSAS, GAGAN
+    Sbas,
+}
+
+impl GnssType {
+    fn name(&self) -> &'static str {
+        match *self {
+            GnssType::Galileo => "Galileo",
+            GnssType::Gps => "GPS",
+            GnssType::Glonass => "GLONASS",
+            GnssType::Beidou => "BeiDou",
+            GnssType::Qzss => "QZSS",
+            GnssType::Sbas => "SBAS",
+        }
+    }
 }
 
 impl fmt::Display for GnssType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {
-            GnssType::Galileo => write!(f, "Galileo"),
-            GnssType::Gps => write!(f, "GPS"),
-            GnssType::Glonass => write!(f, "GLONASS"),
+        f.write_str(self.name())
+    }
+}
+
+impl str::FromStr for GnssType {
+    type Err = NmeaError;
+
+    /// Parse name as printed by `Display`, case is ignored
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            GnssType::Galileo,
+            GnssType::Gps,
+            GnssType::Glonass,
+            GnssType::Beidou,
+            GnssType::Qzss,
+            GnssType::Sbas,
+        ]
+        .iter()
+        .find(|gnss| gnss.name().eq_ignore_ascii_case(s))
+        .cloned()
+        .ok_or(NmeaError::InvalidField("unknown GNSS type"))
+    }
+}
+
+/// Constellation of satellite by its PRN, as numbered in NMEA sentences
+/// with mixed constellations (GNGSA, GNGSV).
+///
+/// GPS 1-32, SBAS 33-64 and 152-158, GLONASS 65-96, QZSS 193-200,
+/// BeiDou 201-237 and 401-437, Galileo 301-336. Unknown numbers give `None`.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::{prn_to_gnss, GnssType};
+///
+/// assert_eq!(prn_to_gnss(70), Some(GnssType::Glonass));
+/// assert_eq!(prn_to_gnss(48), Some(GnssType::Sbas));
+/// assert_eq!(prn_to_gnss(100), None);
+/// ```
+pub fn prn_to_gnss(prn: u32) -> Option<GnssType> {
+    match prn {
+        1..=32 => Some(GnssType::Gps),
+        33..=64 | 152..=158 => Some(GnssType::Sbas),
+        65..=96 => Some(GnssType::Glonass),
+        193..=200 => Some(GnssType::Qzss),
+        201..=237 | 401..=437 => Some(GnssType::Beidou),
+        301..=336 => Some(GnssType::Galileo),
+        _ => None,
+    }
+}
+
+/// ! Talker id, first two characters of sentence address (IEC 61162-1)
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TalkerId {
+    /// GP
+    Gps,
+    /// GL
+    Glonass,
+    /// GA
+    Galileo,
+    /// GB or BD
+    Beidou,
+    /// GQ or QZ
+    Qzss,
+    /// GI or IR
+    NavIC,
+    /// GN, sentence combines data from several constellations
+    GnssCombined,
+    /// AIS station: AB, AD, AI, AN, AR, AS, AT, AX, BS or SA
+    Ais(char, char),
+    /// AG or AP
+    Autopilot,
+    /// EC, Electronic Chart Display & Information System
+    Ecdis,
+    /// HC
+    HeadingCompass,
+    /// HE, north seeking gyro
+    HeadingGyro,
+    /// HN, non north seeking gyro
+    HeadingGyroNonNorthSeeking,
+    /// II
+    IntegratedInstrumentation,
+    /// IN
+    IntegratedNavigation,
+    /// RA, radar and/or radar plotting
+    Radar,
+    /// SD
+    DepthSounder,
+    /// VD, doppler velocity sensor
+    VelocitySensorDoppler,
+    /// VM, water referenced magnetic speed log
+    SpeedLogMagnetic,
+    /// VW, water referenced mechanical speed log
+    SpeedLogMechanical,
+    /// WI
+    WeatherInstruments,
+    /// YX
+    Transducer,
+    /// Manufacturer specific sentence, starts with `P`
+    Proprietary,
+    Other([u8; 2]),
+}
+
+impl TalkerId {
+    pub fn from_bytes(id: &[u8]) -> TalkerId {
+        match id {
+            b"GP" => TalkerId::Gps,
+            b"GL" => TalkerId::Glonass,
+            b"GA" => TalkerId::Galileo,
+            b"GB" | b"BD" => TalkerId::Beidou,
+            b"GQ" | b"QZ" => TalkerId::Qzss,
+            b"GI" | b"IR" => TalkerId::NavIC,
+            b"GN" => TalkerId::GnssCombined,
+            b"AB" | b"AD" | b"AI" | b"AN" | b"AR" | b"AS" | b"AT" | b"AX" | b"BS" | b"SA" => {
+                TalkerId::Ais(char::from(id[0]), char::from(id[1]))
+            }
+            b"AG" | b"AP" => TalkerId::Autopilot,
+            b"EC" => TalkerId::Ecdis,
+            b"HC" => TalkerId::HeadingCompass,
+            b"HE" => TalkerId::HeadingGyro,
+            b"HN" => TalkerId::HeadingGyroNonNorthSeeking,
+            b"II" => TalkerId::IntegratedInstrumentation,
+            b"IN" => TalkerId::IntegratedNavigation,
+            b"RA" => TalkerId::Radar,
+            b"SD" => TalkerId::DepthSounder,
+            b"VD" => TalkerId::VelocitySensorDoppler,
+            b"VM" => TalkerId::SpeedLogMagnetic,
+            b"VW" => TalkerId::SpeedLogMechanical,
+            b"WI" => TalkerId::WeatherInstruments,
+            b"YX" => TalkerId::Transducer,
+            [b'P', ..] => TalkerId::Proprietary,
+            _ => {
+                let mut other = [0; 2];
+                for (to, from) in other.iter_mut().zip(id) {
+                    *to = *from;
+                }
+                TalkerId::Other(other)
+            }
+        }
+    }
+
+    /// Human readable name of device type
+    pub fn description(&self) -> &'static str {
+        match self {
+            TalkerId::Gps => "GPS receiver",
+            TalkerId::Glonass => "GLONASS receiver",
+            TalkerId::Galileo => "Galileo receiver",
+            TalkerId::Beidou => "BeiDou receiver",
+            TalkerId::Qzss => "QZSS receiver",
+            TalkerId::NavIC => "NavIC receiver",
+            TalkerId::GnssCombined => "GNSS receiver",
+            TalkerId::Ais(..) => "AIS station",
+            TalkerId::Autopilot => "Autopilot",
+            TalkerId::Ecdis => "Electronic chart display",
+            TalkerId::HeadingCompass => "Magnetic compass",
+            TalkerId::HeadingGyro => "North seeking gyro",
+            TalkerId::HeadingGyroNonNorthSeeking => "Non north seeking gyro",
+            TalkerId::IntegratedInstrumentation => "Integrated instrumentation",
+            TalkerId::IntegratedNavigation => "Integrated navigation",
+            TalkerId::Radar => "Radar",
+            TalkerId::DepthSounder => "Depth sounder",
+            TalkerId::VelocitySensorDoppler => "Doppler velocity sensor",
+            TalkerId::SpeedLogMagnetic => "Magnetic speed log",
+            TalkerId::SpeedLogMechanical => "Mechanical speed log",
+            TalkerId::WeatherInstruments => "Weather instruments",
+            TalkerId::Transducer => "Transducer",
+            TalkerId::Proprietary => "Proprietary",
+            TalkerId::Other(_) => "Unknown",
         }
     }
 }
@@ -643,23 +1429,27 @@ impl From<char> for FixType {
             '1' => FixType::Gps,
             '2' => FixType::DGps,
             '3' => FixType::Pps,
-            '4' => FixType::Rtk,
-            '5' => FixType::FloatRtk,
+            '4' => FixType::RtkFixed,
+            '5' => FixType::RtkFloat,
             '6' => FixType::Estimated,
             '7' => FixType::Manual,
-            '8' => FixType::Simulation,
+            '8' => FixType::Simulator,
             _ => FixType::Invalid,
         }
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std", feature = "all-sentences"))]
 mod tests {
     use super::parse::checksum;
     use super::*;
     use quickcheck::QuickCheck;
 
     fn check_parsing_lat_lon_in_gga(lat: f64, lon: f64) -> bool {
+        // out of range coordinates are rejected, see `test_parse_lat_lon_out_of_range`
+        if lat.abs() > 90. || lon.abs() > 180. {
+            return true;
+        }
         let lat_min = (lat.abs() * 60.0) % 60.0;
         let lon_min = (lon.abs() * 60.0) % 60.0;
         let mut nmea = Nmea::new();
@@ -688,11 +1478,111 @@ mod tests {
         assert_eq!(FixType::from('1'), FixType::Gps);
         assert_eq!(FixType::from('2'), FixType::DGps);
         assert_eq!(FixType::from('3'), FixType::Pps);
-        assert_eq!(FixType::from('4'), FixType::Rtk);
-        assert_eq!(FixType::from('5'), FixType::FloatRtk);
+        assert_eq!(FixType::from('4'), FixType::RtkFixed);
+        assert_eq!(FixType::from('5'), FixType::RtkFloat);
         assert_eq!(FixType::from('6'), FixType::Estimated);
         assert_eq!(FixType::from('7'), FixType::Manual);
-        assert_eq!(FixType::from('8'), FixType::Simulation);
+        assert_eq!(FixType::from('8'), FixType::Simulator);
+        assert_eq!(FixType::from('9'), FixType::Invalid);
+    }
+
+    #[test]
+    fn test_update_sequence() {
+        let mut nmea = Nmea::new();
+        for (line, sentence_type) in &[
+            (
+                &b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76"[..],
+                SentenceType::GGA,
+            ),
+            (
+                &b"$GPGSA,A,3,10,07,05,02,29,04,08,13,,,,,1.72,1.03,1.38*0A"[..],
+                SentenceType::GSA,
+            ),
+            (
+                &b"$GPGSV,3,1,11,10,63,137,17,07,61,098,15,05,59,290,20,08,54,157,30*70"[..],
+                SentenceType::GSV,
+            ),
+            (
+                &b"$GPRMC,092750.000,A,5321.6802,N,00630.3372,W,0.02,31.66,280511,,,A*43"[..],
+                SentenceType::RMC,
+            ),
+            (
+                &b"$GPVTG,31.66,T,,M,0.02,N,0.04,K,A*09"[..],
+                SentenceType::VTG,
+            ),
+        ] {
+            assert_eq!(nmea.update(line).unwrap(), *sentence_type);
+        }
+        assert_eq!(nmea.fix_timestamp(), NaiveTime::from_hms_opt(9, 27, 50));
+        assert_eq!(nmea.fix_date(), NaiveDate::from_ymd_opt(2011, 5, 28));
+        assert_eq!(nmea.fix_type(), Some(FixType::Gps));
+        assert_eq!(nmea.latitude(), Some(53. + 21.6802 / 60.));
+        assert_eq!(nmea.longitude(), Some(-(6. + 30.3372 / 60.)));
+        assert_eq!(nmea.altitude(), Some(61.7));
+        assert_eq!(nmea.speed_over_ground(), Some(0.02));
+        assert_eq!(nmea.true_course(), Some(31.66));
+        assert_eq!(nmea.fix_satellites(), Some(8));
+        assert_eq!(
+            nmea.fix_satellites_prns(),
+            Some(&[10, 7, 5, 2, 29, 4, 8, 13][..])
+        );
+        assert_eq!(nmea.pdop(), Some(1.72));
+        assert_eq!(nmea.hdop(), Some(1.03));
+        assert_eq!(nmea.vdop(), Some(1.38));
+        assert_eq!(nmea.satellites().len(), 4);
+    }
+
+    #[test]
+    fn test_talker_id() {
+        assert_eq!(TalkerId::from_bytes(b"GP"), TalkerId::Gps);
+        assert_eq!(TalkerId::from_bytes(b"GN"), TalkerId::GnssCombined);
+        assert_eq!(TalkerId::from_bytes(b"GB"), TalkerId::Beidou);
+        assert_eq!(TalkerId::from_bytes(b"BD"), TalkerId::Beidou);
+        assert_eq!(TalkerId::from_bytes(b"AI"), TalkerId::Ais('A', 'I'));
+        assert_eq!(TalkerId::from_bytes(b"PG"), TalkerId::Proprietary);
+        assert_eq!(TalkerId::from_bytes(b"XY"), TalkerId::Other(*b"XY"));
+        for &(id, talker, description) in &[
+            (
+                b"II",
+                TalkerId::IntegratedInstrumentation,
+                "Integrated instrumentation",
+            ),
+            (b"SD", TalkerId::DepthSounder, "Depth sounder"),
+            (b"VW", TalkerId::SpeedLogMechanical, "Mechanical speed log"),
+            (b"WI", TalkerId::WeatherInstruments, "Weather instruments"),
+            (b"HC", TalkerId::HeadingCompass, "Magnetic compass"),
+            (b"AI", TalkerId::Ais('A', 'I'), "AIS station"),
+            (b"GQ", TalkerId::Qzss, "QZSS receiver"),
+            (b"ZZ", TalkerId::Other(*b"ZZ"), "Unknown"),
+        ] {
+            let t = TalkerId::from_bytes(id);
+            assert_eq!(t, talker);
+            assert_eq!(t.description(), description);
+        }
+
+        let s = parse_nmea_sentence(b"$GNGSA,A,1,,,,,,,,,,,,,99.99,99.99,99.99*2E").unwrap();
+        assert_eq!(s.talker(), TalkerId::GnssCombined);
+        let s = parse_nmea_sentence(b"$IIVDR,10.1,T,12.3,M,1.2,N*3A").unwrap();
+        assert_eq!(s.talker(), TalkerId::IntegratedInstrumentation);
+
+        for (line, talker) in &[
+            (
+                "$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76",
+                TalkerId::Gps,
+            ),
+            (
+                "$GNGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*68",
+                TalkerId::GnssCombined,
+            ),
+        ] {
+            match parse_full(line.as_bytes()) {
+                Ok((t, ParseResult::GGA(gga))) => {
+                    assert_eq!(t, *talker);
+                    assert_eq!(gga.fix_satellites, Some(8));
+                }
+                _ => panic!("parse_full failed for {}", line),
+            }
+        }
     }
 
     #[test]
@@ -700,12 +1590,9 @@ mod tests {
         use crate::parse::checksum;
         let valid = "$GNGSA,A,1,,,,,,,,,,,,,99.99,99.99,99.99*2E";
         let invalid = "$GNZDA,165118.00,13,05,2016,00,00*71";
-        assert_eq!(
-            checksum((&valid[1..valid.len() - 3]).as_bytes().iter()),
-            0x2E
-        );
+        assert_eq!(checksum(valid.as_bytes()[1..valid.len() - 3].iter()), 0x2E);
         assert_ne!(
-            checksum((&invalid[1..invalid.len() - 3]).as_bytes().iter()),
+            checksum(invalid.as_bytes()[1..invalid.len() - 3].iter()),
             0x71
         );
     }
@@ -768,6 +1655,17 @@ mod tests {
         assert_eq!(nmea.fix_type(), None);
     }
 
+    #[test]
+    fn test_gga_rtk() {
+        let mut nmea = Nmea::new();
+        nmea.parse("$GPGGA,092750.000,5321.6802,N,00630.3372,W,4,8,1.03,61.7,M,55.2,M,,*73")
+            .unwrap();
+        assert_eq!(nmea.fix_type(), Some(FixType::RtkFixed));
+        nmea.parse("$GPGGA,092750.000,5321.6802,N,00630.3372,W,5,8,1.03,61.7,M,55.2,M,,*72")
+            .unwrap();
+        assert_eq!(nmea.fix_type(), Some(FixType::RtkFloat));
+    }
+
     #[test]
     fn test_gga_gps() {
         use chrono::Timelike;
@@ -859,6 +1757,463 @@ mod tests {
         assert_eq!(nmea.satellites().len(), 7);
     }
 
+    #[test]
+    fn test_gsv_constellations() {
+        let mut nmea = Nmea::new();
+        for line in &[
+            "$GPGSV,1,1,04,10,63,137,17,07,61,098,15,05,59,290,20,08,54,157,30*76",
+            "$GLGSV,1,1,02,65,21,312,25,66,50,075,28*6D",
+            "$GAGSV,1,1,02,01,40,083,46,03,17,308,44*68",
+        ] {
+            assert_eq!(nmea.parse(line).unwrap(), SentenceType::GSV);
+        }
+        let count = |gnss: GnssType| {
+            nmea.satellites()
+                .iter()
+                .filter(|sat| sat.gnss_type == gnss)
+                .count()
+        };
+        assert_eq!(count(GnssType::Gps), 4);
+        assert_eq!(count(GnssType::Glonass), 2);
+        assert_eq!(count(GnssType::Galileo), 2);
+    }
+
+    #[test]
+    fn test_gsv_no_satellites_in_view() {
+        let mut nmea = Nmea::new();
+        nmea.parse("$GPGSV,1,1,02,10,63,137,17,07,61,098,15*79")
+            .unwrap();
+        assert_eq!(nmea.satellites().len(), 2);
+        nmea.parse("$GPGSV,1,1,00*79").unwrap();
+        assert!(nmea.satellites().is_empty());
+    }
+
+    #[test]
+    fn test_gsv_inconsistent_cycle() {
+        let mut nmea = Nmea::new();
+        nmea.parse("$GPGSV,3,1,11,10,63,137,17,07,61,098,15,05,59,290,20,08,54,157,30*70")
+            .unwrap();
+        // dropped sentence from other cycle, reporting 12 satellites in view
+        nmea.parse("$GPGSV,3,2,12,02,39,223,19,13,28,070,17,26,23,252,,04,14,186,14*7A")
+            .unwrap_err();
+        assert_eq!(nmea.satellites().len(), 4);
+        nmea.parse("$GPGSV,3,3,11,29,09,301,24,16,09,020,,36,,,*76")
+            .unwrap();
+        assert_eq!(nmea.satellites().len(), 7);
+
+        // more satellites than in view
+        nmea.parse("$GPGSV,1,1,02,10,63,137,17,07,61,098,15,05,59,290,20*49")
+            .unwrap_err();
+        nmea.parse("$GPGSV,3,0,11,29,09,301,24,16,09,020,,36,,,*75")
+            .unwrap_err();
+        assert_eq!(nmea.satellites().len(), 7);
+    }
+
+    #[test]
+    fn test_gsv_assembler_new_cycle() {
+        let mut assembler = GsvAssembler::new();
+        let gsv = |line: &str| match parse(line.as_bytes()).unwrap() {
+            ParseResult::GSV(data) => data,
+            _ => panic!("GSV expected"),
+        };
+        assembler
+            .merge(gsv("$GPGSV,1,1,02,10,63,137,17,07,61,098,15*79"))
+            .unwrap();
+        assembler
+            .merge(gsv("$GLGSV,1,1,01,74,40,078,43*5B"))
+            .unwrap();
+        assert_eq!(assembler.satellites().len(), 3);
+        assembler
+            .merge(gsv("$GPGSV,1,1,01,10,63,137,17*4F"))
+            .unwrap();
+        assert_eq!(assembler.satellites().len(), 2);
+    }
+
+    #[test]
+    fn test_gsv_assembler_dedup_by_prn() {
+        let gsv = |line: &str| match parse(line.as_bytes()).unwrap() {
+            ParseResult::GSV(data) => data,
+            _ => panic!("GSV expected"),
+        };
+        let l1 = gsv("$GPGSV,1,1,02,10,63,137,17,07,61,098,35,1*66");
+        let l5 = gsv("$GPGSV,1,1,02,10,63,137,27,07,61,098,,8*6A");
+        assert_eq!(l1.signal_id, Some(1));
+        assert_eq!(l5.signal_id, Some(8));
+
+        let mut assembler = GsvAssembler::new();
+        assembler.merge(l1.clone()).unwrap();
+        assembler.merge(l5.clone()).unwrap();
+        assert_eq!(assembler.satellites().len(), 4);
+
+        let mut assembler = GsvAssembler {
+            dedup_by_prn: true,
+            ..GsvAssembler::default()
+        };
+        assembler.merge(l1).unwrap();
+        assembler.merge(l5).unwrap();
+        let mut sats: Vec<_> = assembler
+            .satellites()
+            .iter()
+            .map(|sat| (sat.prn(), sat.snr()))
+            .collect();
+        sats.sort_by_key(|&(prn, _)| prn);
+        assert_eq!(sats, [(7, Some(35.)), (10, Some(27.))]);
+    }
+
+    #[test]
+    fn test_prn_to_gnss() {
+        for (prn, gnss) in vec![
+            (0, None),
+            (1, Some(GnssType::Gps)),
+            (32, Some(GnssType::Gps)),
+            (33, Some(GnssType::Sbas)),
+            (64, Some(GnssType::Sbas)),
+            (65, Some(GnssType::Glonass)),
+            (96, Some(GnssType::Glonass)),
+            (97, None),
+            (151, None),
+            (155, Some(GnssType::Sbas)),
+            (159, None),
+            (195, Some(GnssType::Qzss)),
+            (201, Some(GnssType::Beidou)),
+            (237, Some(GnssType::Beidou)),
+            (238, None),
+            (301, Some(GnssType::Galileo)),
+            (336, Some(GnssType::Galileo)),
+            (401, Some(GnssType::Beidou)),
+            (437, Some(GnssType::Beidou)),
+            (438, None),
+        ] {
+            assert_eq!(prn_to_gnss(prn), gnss, "prn {}", prn);
+        }
+    }
+
+    #[test]
+    fn test_satellite_rinex_id() {
+        let rinex_id = |gnss_type, prn| Satellite::new(gnss_type, prn, None, None, None).rinex_id();
+        assert_eq!(rinex_id(GnssType::Gps, 5), "G05");
+        assert_eq!(rinex_id(GnssType::Gps, 32), "G32");
+        assert_eq!(rinex_id(GnssType::Glonass, 76), "R12");
+        assert_eq!(rinex_id(GnssType::Glonass, 65), "R01");
+        // receivers which report GLONASS slot numbers
+        assert_eq!(rinex_id(GnssType::Glonass, 12), "R12");
+        assert_eq!(rinex_id(GnssType::Galileo, 30), "E30");
+        assert_eq!(rinex_id(GnssType::Galileo, 330), "E30");
+        assert_eq!(rinex_id(GnssType::Beidou, 14), "C14");
+        assert_eq!(rinex_id(GnssType::Beidou, 214), "C14");
+        assert_eq!(rinex_id(GnssType::Beidou, 437), "C37");
+        assert_eq!(rinex_id(GnssType::Qzss, 193), "J01");
+        assert_eq!(rinex_id(GnssType::Qzss, 2), "J02");
+        assert_eq!(rinex_id(GnssType::Sbas, 33), "S20");
+        assert_eq!(rinex_id(GnssType::Sbas, 64), "S51");
+        assert_eq!(rinex_id(GnssType::Sbas, 133), "S33");
+        assert_eq!(rinex_id(GnssType::Sbas, 158), "S58");
+    }
+
+    #[test]
+    fn test_satellite_enu_unit_vector() {
+        for &(elevation, azimuth, expected) in &[
+            (90., 0., [0., 0., 1.]),
+            (90., 123., [0., 0., 1.]),
+            (0., 0., [0., 1., 0.]),
+            (0., 90., [1., 0., 0.]),
+            (0., 180., [0., -1., 0.]),
+            (0., 270., [-1., 0., 0.]),
+            (30., 45., [0.612_372_4, 0.612_372_4, 0.5]),
+        ] {
+            let sat = Satellite::new(GnssType::Gps, 1, Some(elevation), Some(azimuth), None);
+            let v = sat.enu_unit_vector().unwrap();
+            for (a, b) in v.iter().zip(&expected) {
+                assert!((a - b).abs() < 1e-6, "{:?} for {:?}", v, sat);
+            }
+        }
+        let sat = Satellite::new(GnssType::Gps, 1, None, Some(10.), Some(30.));
+        assert_eq!(sat.enu_unit_vector(), None);
+        let sat = Satellite::new(GnssType::Gps, 1, Some(10.), None, Some(30.));
+        assert_eq!(sat.enu_unit_vector(), None);
+    }
+
+    #[test]
+    fn test_gnss_type_from_str() {
+        for gnss in &[
+            GnssType::Galileo,
+            GnssType::Gps,
+            GnssType::Glonass,
+            GnssType::Beidou,
+            GnssType::Qzss,
+            GnssType::Sbas,
+        ] {
+            assert_eq!(gnss.to_string().parse::<GnssType>().as_ref(), Ok(gnss));
+        }
+        assert_eq!(GnssType::Glonass.to_string(), "GLONASS");
+        assert_eq!("gps".parse::<GnssType>(), Ok(GnssType::Gps));
+        assert_eq!("Beidou".parse::<GnssType>(), Ok(GnssType::Beidou));
+        assert_eq!(
+            "NavIC".parse::<GnssType>(),
+            Err(NmeaError::InvalidField("unknown GNSS type"))
+        );
+    }
+
+    #[test]
+    fn test_snr_stats() {
+        let sats = [
+            Satellite::new(GnssType::Gps, 1, Some(49.), Some(196.), Some(41.)),
+            Satellite::new(GnssType::Gps, 3, Some(71.), Some(278.), Some(32.)),
+            Satellite::new(GnssType::Gps, 6, Some(2.), Some(323.), None),
+            Satellite::new(GnssType::Glonass, 72, Some(40.), Some(75.), Some(43.)),
+            Satellite::new(GnssType::Glonass, 87, None, None, Some(20.)),
+        ];
+        assert_eq!(
+            snr_stats(&sats),
+            Some(SnrStats {
+                min: 20.,
+                max: 43.,
+                mean: 34.,
+                count: 4,
+            })
+        );
+        assert_eq!(snr_stats(&sats[2..3]), None);
+        assert_eq!(snr_stats(&[]), None);
+    }
+
+    #[test]
+    fn test_gsa_accumulator() {
+        let mut acc = GsaAccumulator::new();
+        for line in &[
+            "$GPGSA,A,3,23,31,22,16,03,07,,,,,,,1.8,1.1,1.4*3E",
+            "$BDGSA,A,3,214,,,,,,,,,,,,1.8,1.1,1.4*18",
+            "$GNGSA,A,3,31,26,21,,,,,,,,,,3.77,2.55,2.77*1A",
+        ] {
+            acc.update(&parse(line.as_bytes()).unwrap());
+        }
+        assert_eq!(acc.sentences().len(), 3);
+        assert_eq!(
+            acc.fix_sats_prn(),
+            vec![
+                (23, Some(GnssType::Gps)),
+                (31, Some(GnssType::Gps)),
+                (22, Some(GnssType::Gps)),
+                (16, Some(GnssType::Gps)),
+                (3, Some(GnssType::Gps)),
+                (7, Some(GnssType::Gps)),
+                (214, Some(GnssType::Beidou)),
+                (31, None),
+                (26, None),
+                (21, None),
+            ]
+        );
+        assert_eq!(acc.pdop(), Some(1.8));
+        assert_eq!(acc.hdop(), Some(1.1));
+        assert_eq!(acc.vdop(), Some(1.4));
+
+        // RMC closes cycle, but keeps its data until next GSA
+        acc.update(
+            &parse(b"$GPRMC,092750.000,A,5321.6802,N,00630.3372,W,0.02,31.66,280511,,,A*43")
+                .unwrap(),
+        );
+        assert_eq!(acc.sentences().len(), 3);
+        acc.update(&parse(b"$GPGSA,A,3,23,31,22,16,03,07,,,,,,,1.8,1.1,1.4*3E").unwrap());
+        assert_eq!(acc.sentences().len(), 1);
+        assert_eq!(acc.fix_sats_prn().len(), 6);
+    }
+
+    #[test]
+    fn test_mark_used_in_fix() {
+        let mut nmea = Nmea::new();
+        for line in &[
+            "$GPGSV,1,1,04,10,63,137,17,07,61,098,15,05,59,290,20,08,54,157,30*76",
+            "$GLGSV,1,1,02,65,21,312,25,66,50,075,28*6D",
+            "$GPGSA,A,3,10,08,,,,,,,,,,,1.8,1.1,1.4*37",
+        ] {
+            nmea.parse(line).unwrap();
+        }
+        let used: Vec<(u32, bool)> = nmea
+            .satellites()
+            .iter()
+            .map(|sat| (sat.prn(), sat.used_in_fix()))
+            .collect();
+        // no GSA for GLONASS yet
+        assert_eq!(
+            used,
+            vec![
+                (10, true),
+                (7, false),
+                (5, false),
+                (8, true),
+                (65, false),
+                (66, false)
+            ]
+        );
+
+        nmea.parse("$GLGSA,A,3,66,,,,,,,,,,,,1.8,1.1,1.4*22")
+            .unwrap();
+        let used: Vec<u32> = nmea
+            .satellites()
+            .iter()
+            .filter(|sat| sat.used_in_fix())
+            .map(Satellite::prn)
+            .collect();
+        assert_eq!(used, vec![10, 8, 66]);
+
+        // GSV after GSA keeps marks
+        nmea.parse("$GPGSV,1,1,04,10,63,137,17,07,61,098,15,05,59,290,20,08,54,157,30*76")
+            .unwrap();
+        assert_eq!(
+            nmea.satellites()
+                .iter()
+                .filter(|sat| sat.used_in_fix())
+                .count(),
+            3
+        );
+
+        let mut sats = [Satellite::new(GnssType::Gps, 10, None, None, None)];
+        GsaAccumulator::new().mark_used_in_fix(&mut sats);
+        assert!(!sats[0].used_in_fix());
+        assert_eq!(format!("{:?}", sats[0]), "[Gps,10,None,None,None,false]");
+    }
+
+    #[test]
+    fn test_gsa_accumulator_gngsa_pair() {
+        let mut nmea = Nmea::new();
+        for line in &[
+            "$GNGSA,A,3,31,26,21,,,,,,,,,,3.77,2.55,2.77*1A",
+            "$GNGSA,A,3,75,86,87,,,,,,,,,,3.77,2.55,2.77*1C",
+            "$GNGSA,A,3,75,86,87,,,,,,,,,,3.77,2.55,2.77*1C",
+        ] {
+            nmea.parse(line).unwrap();
+        }
+        assert_eq!(nmea.fix_satellites_prns, Some(vec![31, 26, 21, 75, 86, 87]));
+        assert_eq!(nmea.hdop(), Some(2.55));
+
+        let mut acc = GsaAccumulator::new();
+        for line in &[
+            "$GNGSA,A,3,31,26,21,,,,,,,,,,3.77,2.55,2.77,1*07",
+            "$GNGSA,A,3,75,86,87,,,,,,,,,,3.77,2.55,2.77,2*02",
+        ] {
+            acc.update(&parse(line.as_bytes()).unwrap());
+        }
+        assert_eq!(
+            acc.fix_sats_prn(),
+            vec![
+                (31, Some(GnssType::Gps)),
+                (26, Some(GnssType::Gps)),
+                (21, Some(GnssType::Gps)),
+                (75, Some(GnssType::Glonass)),
+                (86, Some(GnssType::Glonass)),
+                (87, Some(GnssType::Glonass)),
+            ]
+        );
+        assert_eq!(acc.satellites_used(), 6);
+        assert_eq!(acc.sentences()[0].satellites_used(), 3);
+        // the same GPS PRNs repeated by next GNGSA of cycle
+        acc.update(&parse(b"$GNGSA,A,3,31,26,21,,,,,,,,,,3.77,2.55,2.77,1*07").unwrap());
+        assert_eq!(acc.satellites_used(), 6);
+    }
+
+    #[test]
+    fn test_satellites_used_by_gnss() {
+        let mut gsa = Vec::new();
+        let mut gsv = Vec::new();
+        for line in &[
+            // GPS, SBAS and QZSS in one GNGSA
+            "$GNGSA,A,3,05,12,46,193,,,,,,,,,1.8,1.1,1.4*1F",
+            "$GNGSA,A,3,70,71,,,,,,,,,,,1.8,1.1,1.4*21",
+            "$GAGSA,A,3,01,03,,,,,,,,,,,1.8,1.1,1.4*2D",
+            // the same PRN reported twice counted once
+            "$GNGSA,A,3,05,,,,,,,,,,,,1.8,1.1,1.4*25",
+            "$GAGSV,1,1,02,01,40,083,46,03,17,308,44*68",
+        ] {
+            match parse(line.as_bytes()).unwrap() {
+                ParseResult::GSA(data) => gsa.push(data),
+                ParseResult::GSV(data) => gsv.push(data),
+                _ => panic!("expected GSA or GSV"),
+            }
+        }
+        let mut expected = HashMap::new();
+        expected.insert(GnssType::Gps, 2);
+        expected.insert(GnssType::Sbas, 1);
+        expected.insert(GnssType::Qzss, 1);
+        expected.insert(GnssType::Glonass, 2);
+        expected.insert(GnssType::Galileo, 2);
+        assert_eq!(satellites_used_by_gnss(&gsa, &gsv), expected);
+
+        // GSV resolves PRN of other numbering in GNGSA
+        let gsa = match parse(b"$GNGSA,A,3,01,03,,,,,,,,,,,1.8,1.1,1.4*22").unwrap() {
+            ParseResult::GSA(data) => data,
+            _ => panic!("expected GSA"),
+        };
+        let mut expected = HashMap::new();
+        expected.insert(GnssType::Galileo, 2);
+        assert_eq!(satellites_used_by_gnss(&[gsa], &gsv), expected);
+
+        // GPGSA reports SBAS and QZSS with GPS, GSA and GSV of Beidou and QZSS talkers
+        let mut gsa = Vec::new();
+        let mut gsv = Vec::new();
+        for line in &[
+            "$GPGSA,A,3,05,12,46,194,,,,,,,,,1.8,1.1,1.4*06",
+            "$BDGSA,A,3,201,,,,,,,,,,,,1.8,1.1,1.4*1C",
+            // NMEA 4.11 QZSS numbering from 1
+            "$QZGSA,A,3,01,02,,,,,,,,,,,1.8,1.1,1.4*21",
+            "$BDGSV,1,1,01,201,40,083,46*67",
+            "$QZGSV,1,1,01,194,40,083,46*65",
+        ] {
+            match parse(line.as_bytes()).unwrap() {
+                ParseResult::GSA(data) => gsa.push(data),
+                ParseResult::GSV(data) => gsv.push(data),
+                _ => panic!("expected GSA or GSV"),
+            }
+        }
+        assert_eq!(gsv[0].gnss_type, GnssType::Beidou);
+        assert_eq!(gsv[1].gnss_type, GnssType::Qzss);
+        let mut expected = HashMap::new();
+        expected.insert(GnssType::Gps, 2);
+        expected.insert(GnssType::Sbas, 1);
+        expected.insert(GnssType::Qzss, 3);
+        expected.insert(GnssType::Beidou, 1);
+        assert_eq!(satellites_used_by_gnss(&gsa, &gsv), expected);
+    }
+
+    #[test]
+    fn test_gsa_cycle() {
+        let mut cycle = GsaCycle::new();
+        for line in &[
+            "$GNGSA,A,3,23,31,22,16,03,07,,,,,,,1.8,1.1,1.4*20",
+            "$GNGSA,A,3,214,,,,,,,,,,,,1.8,1.1,1.4*17",
+        ] {
+            match parse(line.as_bytes()).unwrap() {
+                ParseResult::GSA(gsa) => cycle.merge(gsa),
+                _ => panic!("expected GSA"),
+            }
+        }
+        assert_eq!(cycle.sentences().len(), 2);
+        assert_eq!(
+            cycle.fix_sats_prn(),
+            vec![
+                (23, Some(GnssType::Gps)),
+                (31, Some(GnssType::Gps)),
+                (22, Some(GnssType::Gps)),
+                (16, Some(GnssType::Gps)),
+                (3, Some(GnssType::Gps)),
+                (7, Some(GnssType::Gps)),
+                (214, Some(GnssType::Beidou)),
+            ]
+        );
+        assert_eq!(cycle.satellites_used(), 7);
+        assert_eq!(cycle.pdop(), Some(1.8));
+        assert_eq!(cycle.hdop(), Some(1.1));
+        assert_eq!(cycle.vdop(), Some(1.4));
+
+        // GPS again, next epoch
+        match parse(b"$GNGSA,A,3,23,31,22,16,,,,,,,,,2.0,1.3,1.5*2C").unwrap() {
+            ParseResult::GSA(gsa) => cycle.merge(gsa),
+            _ => panic!("expected GSA"),
+        }
+        assert_eq!(cycle.sentences().len(), 1);
+        assert_eq!(cycle.fix_sats_prn().len(), 4);
+        assert_eq!(cycle.satellites_used(), 4);
+        assert_eq!(cycle.hdop(), Some(1.3));
+    }
+
     #[test]
     fn test_parse() {
         let sentences = [
@@ -898,7 +2253,7 @@ mod tests {
             let mut nmea = Nmea::create_for_navigation(
                 [SentenceType::RMC, SentenceType::GGA]
                     .iter()
-                    .map(|v| v.clone())
+                    .cloned()
                     .collect(),
             )
             .unwrap();
@@ -906,92 +2261,92 @@ mod tests {
                 (
                     "$GPRMC,123308.2,A,5521.76474,N,03731.92553,E,000.48,071.9,090317,010.2,E,A*3B",
                     FixType::Invalid,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 200)),
+                    NaiveTime::from_hms_milli_opt(12, 33, 8, 200),
                 ),
                 (
                     "$GPGGA,123308.2,5521.76474,N,03731.92553,E,1,08,2.2,211.5,M,13.1,M,,*52",
                     FixType::Gps,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 200)),
+                    NaiveTime::from_hms_milli_opt(12, 33, 8, 200),
                 ),
                 (
                     "$GPVTG,071.9,T,061.7,M,000.48,N,0000.88,K,A*10",
                     FixType::Invalid,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 200)),
+                    NaiveTime::from_hms_milli_opt(12, 33, 8, 200),
                 ),
                 (
                     "$GPRMC,123308.3,A,5521.76474,N,03731.92553,E,000.51,071.9,090317,010.2,E,A*32",
                     FixType::Invalid,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 300)),
+                    NaiveTime::from_hms_milli_opt(12, 33, 8, 300),
                 ),
                 (
                     "$GPGGA,123308.3,5521.76474,N,03731.92553,E,1,08,2.2,211.5,M,13.1,M,,*53",
                     FixType::Gps,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 300)),
+                    NaiveTime::from_hms_milli_opt(12, 33, 8, 300),
                 ),
                 (
                     "$GPVTG,071.9,T,061.7,M,000.51,N,0000.94,K,A*15",
                     FixType::Invalid,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 300)),
+                    NaiveTime::from_hms_milli_opt(12, 33, 8, 300),
                 ),
                 (
                     "$GPRMC,123308.4,A,5521.76474,N,03731.92553,E,000.54,071.9,090317,010.2,E,A*30",
                     FixType::Invalid,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 400)),
+                    NaiveTime::from_hms_milli_opt(12, 33, 8, 400),
                 ),
                 (
                     "$GPGGA,123308.4,5521.76474,N,03731.92553,E,1,08,2.2,211.5,M,13.1,M,,*54",
                     FixType::Gps,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 400)),
+                    NaiveTime::from_hms_milli_opt(12, 33, 8, 400),
                 ),
                 (
                     "$GPVTG,071.9,T,061.7,M,000.54,N,0001.00,K,A*1C",
                     FixType::Invalid,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 400)),
+                    NaiveTime::from_hms_milli_opt(12, 33, 8, 400),
                 ),
                 (
                     "$GPRMC,123308.5,A,5521.76474,N,03731.92553,E,000.57,071.9,090317,010.2,E,A*32",
                     FixType::Invalid,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 500)),
+                    NaiveTime::from_hms_milli_opt(12, 33, 8, 500),
                 ),
                 (
                     "$GPGGA,123308.5,5521.76474,N,03731.92553,E,1,08,2.2,211.5,M,13.1,M,,*55",
                     FixType::Gps,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 500)),
+                    NaiveTime::from_hms_milli_opt(12, 33, 8, 500),
                 ),
                 (
                     "$GPVTG,071.9,T,061.7,M,000.57,N,0001.05,K,A*1A",
                     FixType::Invalid,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 500)),
+                    NaiveTime::from_hms_milli_opt(12, 33, 8, 500),
                 ),
                 (
                     "$GPRMC,123308.6,A,5521.76474,N,03731.92553,E,000.58,071.9,090317,010.2,E,A*3E",
                     FixType::Invalid,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 600)),
+                    NaiveTime::from_hms_milli_opt(12, 33, 8, 600),
                 ),
                 (
                     "$GPGGA,123308.6,5521.76474,N,03731.92553,E,1,08,2.2,211.5,M,13.1,M,,*56",
                     FixType::Gps,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 600)),
+                    NaiveTime::from_hms_milli_opt(12, 33, 8, 600),
                 ),
                 (
                     "$GPVTG,071.9,T,061.7,M,000.58,N,0001.08,K,A*18",
                     FixType::Invalid,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 600)),
+                    NaiveTime::from_hms_milli_opt(12, 33, 8, 600),
                 ),
                 (
                     "$GPRMC,123308.7,A,5521.76474,N,03731.92553,E,000.59,071.9,090317,010.2,E,A*3E",
                     FixType::Invalid,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 700)),
+                    NaiveTime::from_hms_milli_opt(12, 33, 8, 700),
                 ),
                 (
                     "$GPGGA,123308.7,5521.76474,N,03731.92553,E,1,08,2.2,211.5,M,13.1,M,,*57",
                     FixType::Gps,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 700)),
+                    NaiveTime::from_hms_milli_opt(12, 33, 8, 700),
                 ),
                 (
                     "$GPVTG,071.9,T,061.7,M,000.59,N,0001.09,K,A*18",
                     FixType::Invalid,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 700)),
+                    NaiveTime::from_hms_milli_opt(12, 33, 8, 700),
                 ),
             ];
 
@@ -1006,7 +2361,7 @@ mod tests {
             let mut nmea = Nmea::create_for_navigation(
                 [SentenceType::RMC, SentenceType::GGA]
                     .iter()
-                    .map(|v| v.clone())
+                    .cloned()
                     .collect(),
             )
             .unwrap();
@@ -1014,17 +2369,17 @@ mod tests {
                 (
                     "$GPRMC,123308.2,A,5521.76474,N,03731.92553,E,000.48,071.9,090317,010.2,E,A*3B",
                     FixType::Invalid,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 200)),
+                    NaiveTime::from_hms_milli_opt(12, 33, 8, 200),
                 ),
                 (
                     "$GPRMC,123308.3,A,5521.76474,N,03731.92553,E,000.51,071.9,090317,010.2,E,A*32",
                     FixType::Invalid,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 300)),
+                    NaiveTime::from_hms_milli_opt(12, 33, 8, 300),
                 ),
                 (
                     "$GPGGA,123308.3,5521.76474,N,03731.92553,E,1,08,2.2,211.5,M,13.1,M,,*53",
                     FixType::Gps,
-                    Some(NaiveTime::from_hms_milli(12, 33, 8, 300)),
+                    NaiveTime::from_hms_milli_opt(12, 33, 8, 300),
                 ),
             ];
 
@@ -1053,7 +2408,7 @@ mod tests {
         let mut nmea = Nmea::create_for_navigation(
             [SentenceType::RMC, SentenceType::GGA]
                 .iter()
-                .map(|v| v.clone())
+                .cloned()
                 .collect(),
         )
         .unwrap();
@@ -1076,6 +2431,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::upper_case_acronyms)]
     fn test_define_sentence_type_enum() {
         define_sentence_type_enum!(TestEnum { AAA, BBB });
 
@@ -0,0 +1,140 @@
+use chrono::{NaiveDate, NaiveTime};
+
+use crate::{FixType, ParseResult};
+
+/// All data which sentences can report in one flat struct,
+/// for FFI or table like export. Fields not provided by
+/// sentence are `None`.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Observation {
+    pub fix_time: Option<NaiveTime>,
+    pub fix_date: Option<NaiveDate>,
+    pub fix_type: Option<FixType>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub altitude: Option<f32>,
+    pub geoid_height: Option<f32>,
+    pub speed_over_ground: Option<f32>,
+    pub true_course: Option<f32>,
+    pub fix_satellites: Option<u32>,
+    pub hdop: Option<f32>,
+    pub vdop: Option<f32>,
+    pub pdop: Option<f32>,
+}
+
+impl From<ParseResult> for Observation {
+    fn from(result: ParseResult) -> Self {
+        match result {
+            #[cfg(feature = "gga")]
+            ParseResult::GGA(gga) => Observation {
+                fix_time: gga.fix_time,
+                fix_type: gga.fix_type,
+                latitude: gga.latitude,
+                longitude: gga.longitude,
+                fix_satellites: gga.fix_satellites,
+                hdop: gga.hdop,
+                altitude: gga.altitude,
+                geoid_height: gga.geoid_height,
+                ..Observation::default()
+            },
+            #[cfg(feature = "rmc")]
+            ParseResult::RMC(rmc) => Observation {
+                fix_time: rmc.fix_time,
+                fix_date: rmc.fix_date,
+                latitude: rmc.lat,
+                longitude: rmc.lon,
+                speed_over_ground: rmc.speed_over_ground,
+                true_course: rmc.true_course,
+                ..Observation::default()
+            },
+            #[cfg(feature = "vtg")]
+            ParseResult::VTG(vtg) => Observation {
+                speed_over_ground: vtg.speed_over_ground,
+                true_course: vtg.true_course,
+                ..Observation::default()
+            },
+            #[cfg(feature = "gll")]
+            ParseResult::GLL(gll) => Observation {
+                fix_time: Some(gll.fix_time),
+                latitude: Some(gll.latitude),
+                longitude: Some(gll.longitude),
+                ..Observation::default()
+            },
+            #[cfg(feature = "gns")]
+            ParseResult::GNS(gns) => Observation {
+                fix_time: gns.fix_time,
+                latitude: gns.latitude,
+                longitude: gns.longitude,
+                fix_satellites: gns.fix_satellites,
+                hdop: gns.hdop,
+                altitude: gns.altitude,
+                geoid_height: gns.geoid_height,
+                ..Observation::default()
+            },
+            #[cfg(feature = "rma")]
+            ParseResult::RMA(rma) => Observation {
+                latitude: rma.latitude,
+                longitude: rma.longitude,
+                speed_over_ground: rma.speed_over_ground,
+                true_course: rma.true_course,
+                ..Observation::default()
+            },
+            #[cfg(feature = "gsa")]
+            ParseResult::GSA(gsa) => Observation {
+                hdop: gsa.hdop,
+                vdop: gsa.vdop,
+                pdop: gsa.pdop,
+                ..Observation::default()
+            },
+            _ => Observation::default(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "all-sentences"))]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    #[test]
+    fn test_observation_from_gga() {
+        let gga = parse(b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76")
+            .unwrap();
+        assert_eq!(
+            Observation::from(gga),
+            Observation {
+                fix_time: NaiveTime::from_hms_opt(9, 27, 50),
+                fix_type: Some(FixType::Gps),
+                latitude: Some(53. + 21.6802 / 60.),
+                longitude: Some(-(6. + 30.3372 / 60.)),
+                altitude: Some(61.7),
+                geoid_height: Some(55.2),
+                fix_satellites: Some(8),
+                hdop: Some(1.03),
+                ..Observation::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_observation_from_rmc() {
+        let rmc = parse(b"$GPRMC,092750.000,A,5321.6802,N,00630.3372,W,0.02,31.66,280511,,,A*43")
+            .unwrap();
+        assert_eq!(
+            Observation::from(rmc),
+            Observation {
+                fix_time: NaiveTime::from_hms_opt(9, 27, 50),
+                fix_date: NaiveDate::from_ymd_opt(2011, 5, 28),
+                latitude: Some(53. + 21.6802 / 60.),
+                longitude: Some(-(6. + 30.3372 / 60.)),
+                speed_over_ground: Some(0.02),
+                true_course: Some(31.66),
+                ..Observation::default()
+            }
+        );
+
+        let hdm = parse(b"$HCHDM,238.5,M*25").unwrap();
+        assert_eq!(Observation::from(hdm), Observation::default());
+    }
+}
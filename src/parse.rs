@@ -1,18 +1,146 @@
-use std::str;
+#[cfg(any(feature = "gns", feature = "tll", feature = "ztg"))]
+use alloc::string::ToString;
+use alloc::{string::String, vec::Vec};
+#[cfg(any(
+    feature = "gga",
+    feature = "gll",
+    feature = "gsa",
+    feature = "gsv",
+    feature = "rmc",
+    feature = "vtg"
+))]
+use core::convert::TryFrom;
+use core::str;
 
-use chrono::{NaiveDate, NaiveTime};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+#[cfg(any(
+    feature = "gga",
+    feature = "ggk",
+    feature = "gns",
+    feature = "gsa",
+    feature = "mta",
+    feature = "mtw",
+    feature = "rma",
+    feature = "rmc",
+    feature = "tll"
+))]
 use nom::branch::alt;
-use nom::bytes::complete::{tag, take, take_until, take_while1};
-use nom::character::complete::{char, digit1, one_of};
-use nom::combinator::{all_consuming, cond, map, map_parser, map_res, opt, rest_len, value};
-use nom::multi::many0;
-use nom::number::complete::{double, float};
-use nom::sequence::{preceded, terminated, tuple};
+#[cfg(feature = "gns")]
+use nom::bytes::complete::take_while;
+#[cfg(any(feature = "gga", feature = "ggk", feature = "gns", feature = "gsa"))]
+use nom::bytes::complete::take_while1;
+use nom::bytes::complete::{tag, take, take_until};
+#[cfg(any(
+    feature = "gga",
+    feature = "ggk",
+    feature = "gns",
+    feature = "gsa",
+    feature = "gsv",
+    feature = "tll"
+))]
+use nom::character::complete::digit1;
+use nom::character::complete::{char, one_of};
+#[cfg(feature = "gsa")]
+use nom::combinator::all_consuming;
+#[cfg(any(
+    feature = "gga",
+    feature = "ggk",
+    feature = "gll",
+    feature = "gns",
+    feature = "gsa",
+    feature = "rma",
+    feature = "rmc",
+    feature = "tll"
+))]
+use nom::combinator::map;
+#[cfg(any(
+    feature = "gga",
+    feature = "ggk",
+    feature = "gll",
+    feature = "gns",
+    feature = "gst",
+    feature = "rmc",
+    feature = "tll",
+    feature = "ztg"
+))]
+use nom::combinator::map_parser;
+#[cfg(any(
+    feature = "gga",
+    feature = "ggk",
+    feature = "gll",
+    feature = "gns",
+    feature = "gsa",
+    feature = "gst",
+    feature = "gsv",
+    feature = "hdm",
+    feature = "mta",
+    feature = "mtw",
+    feature = "rma",
+    feature = "rmc",
+    feature = "tll",
+    feature = "vdr",
+    feature = "vtg",
+    feature = "ztg"
+))]
+use nom::combinator::opt;
+#[cfg(feature = "gga")]
+use nom::combinator::peek;
+#[cfg(feature = "ztg")]
+use nom::combinator::rest;
+#[cfg(any(feature = "gga", feature = "gsa", feature = "mta", feature = "mtw"))]
+use nom::combinator::value;
+#[cfg(feature = "gsv")]
+use nom::combinator::{cond, rest_len};
+use nom::combinator::{map_res, verify};
+#[cfg(feature = "gsa")]
+use nom::multi::fold_many0;
+#[cfg(any(
+    feature = "gga",
+    feature = "ggk",
+    feature = "gll",
+    feature = "gns",
+    feature = "gst",
+    feature = "rma",
+    feature = "rmc",
+    feature = "tll",
+    feature = "ztg"
+))]
+use nom::number::complete::double;
+#[cfg(any(
+    feature = "gga",
+    feature = "ggk",
+    feature = "gns",
+    feature = "gst",
+    feature = "hdm",
+    feature = "mta",
+    feature = "mtw",
+    feature = "rma",
+    feature = "rmc",
+    feature = "vdr",
+    feature = "vtg"
+))]
+use nom::number::complete::float;
+#[cfg(any(
+    feature = "gga",
+    feature = "ggk",
+    feature = "gll",
+    feature = "gns",
+    feature = "gst",
+    feature = "rmc",
+    feature = "tll",
+    feature = "ztg"
+))]
+use nom::sequence::tuple;
+use nom::sequence::{preceded, terminated};
 use nom::IResult;
 
-use crate::{FixType, GnssType, Satellite, SentenceType};
+use crate::error::NmeaError;
+use crate::heading::normalize_degrees;
+use crate::{prn_to_gnss, FixType, GnssType, Satellite, SentenceType, TalkerId};
 
 pub struct NmeaSentence<'a> {
+    /// `$` for usual sentences, `!` for encapsulated ones like AIS
+    pub start_delimiter: u8,
     pub talker_id: &'a [u8],
     pub message_id: &'a [u8],
     pub data: &'a [u8],
@@ -25,35 +153,135 @@ impl<'a> NmeaSentence<'a> {
             self.talker_id
                 .iter()
                 .chain(self.message_id.iter())
-                .chain(&[b','])
+                .chain(b",")
                 .chain(self.data.iter()),
         )
     }
+
+    pub fn talker(&self) -> TalkerId {
+        TalkerId::from_bytes(self.talker_id)
+    }
+
+    /// Talker id as string, like `"GP"`, fails only if sentence
+    /// was constructed from non utf-8 bytes
+    pub fn talker_id_str(&self) -> Result<&'a str, NmeaError> {
+        str::from_utf8(self.talker_id)
+            .map_err(|_| NmeaError::InvalidField("talker id is not valid utf-8"))
+    }
+
+    /// Message id as string, like `"GGA"`, see `talker_id_str`
+    pub fn message_id_str(&self) -> Result<&'a str, NmeaError> {
+        str::from_utf8(self.message_id)
+            .map_err(|_| NmeaError::InvalidField("message id is not valid utf-8"))
+    }
+
+    /// Type of sentence, `SentenceType::None` for unknown ones
+    /// (raw id still available as `message_id`)
+    pub fn sentence_type(&self) -> Result<SentenceType, NmeaError> {
+        SentenceType::try_from(self.message_id).map_err(|_| NmeaError::UnknownSentenceType)
+    }
+
+    /// Comma separated fields of `data`, empty fields are preserved,
+    /// to decode sentences not supported by this crate
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nmea::parse_nmea_sentence;
+    ///
+    /// let s = parse_nmea_sentence(b"$PGRMZ,246,f,3*1B").unwrap();
+    /// let fields: Vec<&[u8]> = s.fields().collect();
+    /// assert_eq!(fields, [&b"246"[..], b"f", b"3"]);
+    /// ```
+    pub fn fields(&self) -> impl Iterator<Item = &'a [u8]> {
+        self.data.split(|&c| c == b',')
+    }
+
+    /// Copy sentence into `OwnedNmeaSentence`, so it can be stored
+    /// without keeping original buffer alive
+    pub fn to_owned(&self) -> OwnedNmeaSentence {
+        let mut talker_id = [0; 2];
+        for (to, from) in talker_id.iter_mut().zip(self.talker_id) {
+            *to = *from;
+        }
+        let mut message_id = [0; 3];
+        for (to, from) in message_id.iter_mut().zip(self.message_id) {
+            *to = *from;
+        }
+        OwnedNmeaSentence {
+            start_delimiter: self.start_delimiter,
+            talker_id,
+            message_id,
+            data: self.data.to_vec(),
+            checksum: self.checksum,
+        }
+    }
+}
+
+/// Owned version of `NmeaSentence`
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnedNmeaSentence {
+    pub start_delimiter: u8,
+    pub talker_id: [u8; 2],
+    pub message_id: [u8; 3],
+    pub data: Vec<u8>,
+    pub checksum: u8,
+}
+
+impl OwnedNmeaSentence {
+    /// Borrow as `NmeaSentence` to pass it to sentence parsers
+    pub fn as_sentence(&self) -> NmeaSentence<'_> {
+        NmeaSentence {
+            start_delimiter: self.start_delimiter,
+            talker_id: &self.talker_id,
+            message_id: &self.message_id,
+            data: &self.data,
+            checksum: self.checksum,
+        }
+    }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GsvData {
     pub gnss_type: GnssType,
     pub number_of_sentences: u16,
     pub sentence_num: u16,
-    pub _sats_in_view: u16,
+    pub sats_in_view: u16,
     pub sats_info: [Option<Satellite>; 4],
+    /// Signal id of NMEA 4.11, like 1 for GPS L1 C/A and 8 for L5,
+    /// `None` for older sentences without it
+    pub signal_id: Option<u8>,
+}
+
+impl GsvData {
+    /// Number of satellites in sentence with known SNR,
+    /// i.e. actually tracked by receiver
+    pub fn tracked_count(&self) -> usize {
+        self.sats_info
+            .iter()
+            .flatten()
+            .filter(|sat| sat.snr.is_some())
+            .count()
+    }
 }
 
 pub fn checksum<'a, I: Iterator<Item = &'a u8>>(bytes: I) -> u8 {
     bytes.fold(0, |c, x| c ^ *x)
 }
 
-fn parse_hex(data: &[u8]) -> std::result::Result<u8, &'static str> {
-    u8::from_str_radix(unsafe { str::from_utf8_unchecked(data) }, 16)
-        .map_err(|_| "Failed to parse checksum as hex number")
+fn parse_hex(data: &[u8]) -> core::result::Result<u8, &'static str> {
+    let data = str::from_utf8(data).map_err(|_| "Checksum is not valid utf-8")?;
+    u8::from_str_radix(data, 16).map_err(|_| "Failed to parse checksum as hex number")
 }
 
 fn parse_checksum(i: &[u8]) -> IResult<&[u8], u8> {
     map_res(preceded(char('*'), take(2usize)), parse_hex)(i)
 }
 
-fn do_parse_nmea_sentence(i: &[u8]) -> IResult<&[u8], NmeaSentence> {
-    let (i, talker_id) = preceded(char('$'), take(2usize))(i)?;
+fn do_parse_nmea_sentence(i: &[u8]) -> IResult<&[u8], NmeaSentence<'_>> {
+    let (i, start_delimiter) = one_of("$!")(i)?;
+    let (i, talker_id) = take(2usize)(i)?;
     let (i, message_id) = take(3usize)(i)?;
     let (i, _) = char(',')(i)?;
     let (i, data) = take_until("*")(i)?;
@@ -62,6 +290,7 @@ fn do_parse_nmea_sentence(i: &[u8]) -> IResult<&[u8], NmeaSentence> {
     Ok((
         i,
         NmeaSentence {
+            start_delimiter: start_delimiter as u8,
             talker_id,
             message_id,
             data,
@@ -70,7 +299,161 @@ fn do_parse_nmea_sentence(i: &[u8]) -> IResult<&[u8], NmeaSentence> {
     ))
 }
 
-pub fn parse_nmea_sentence(sentence: &[u8]) -> std::result::Result<NmeaSentence, String> {
+/// Proprietary sentence: `$P`, 3 chars manufacturer id and data in format
+/// of manufacturer. Address field is not split into talker and message id
+/// like in `NmeaSentence`, because vendors use different length of it
+/// (`$PGRMZ`, `$PUBX`, `$PSTI`).
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ProprietarySentence<'a> {
+    pub manufacturer: [u8; 3],
+    /// Everything after manufacturer id up to `*`,
+    /// for example `Z,246,f,3` for `$PGRMZ,246,f,3*1B`
+    pub data: &'a [u8],
+    pub checksum: u8,
+}
+
+impl<'a> ProprietarySentence<'a> {
+    pub fn calc_checksum(&self) -> u8 {
+        checksum(
+            b"P".iter()
+                .chain(self.manufacturer.iter())
+                .chain(self.data.iter()),
+        )
+    }
+}
+
+fn do_parse_proprietary_sentence(i: &[u8]) -> IResult<&[u8], ProprietarySentence<'_>> {
+    let (i, id) = preceded(
+        tag("$P"),
+        verify(take(3usize), |id: &[u8]| {
+            id.iter().all(u8::is_ascii_alphanumeric)
+        }),
+    )(i)?;
+    let (i, data) = take_until("*")(i)?;
+    let (i, checksum) = parse_checksum(i)?;
+
+    let mut manufacturer = [0; 3];
+    manufacturer.copy_from_slice(id);
+    Ok((
+        i,
+        ProprietarySentence {
+            manufacturer,
+            data,
+            checksum,
+        },
+    ))
+}
+
+/// Parse frame of proprietary `$P` sentence, checksum is not checked
+///
+/// # Examples
+///
+/// ```
+/// use nmea::parse_proprietary_sentence;
+///
+/// let s = parse_proprietary_sentence(b"$PGRMZ,246,f,3*1B").unwrap();
+/// assert_eq!(&s.manufacturer, b"GRM");
+/// assert_eq!(s.data, b"Z,246,f,3");
+/// assert_eq!(s.checksum, s.calc_checksum());
+/// ```
+pub fn parse_proprietary_sentence(
+    sentence: &[u8],
+) -> core::result::Result<ProprietarySentence<'_>, NmeaError> {
+    // see parse_nmea_sentence
+    if sentence.len() > 102 {
+        return Err(NmeaError::TooLong);
+    }
+    let res = do_parse_proprietary_sentence(sentence)
+        .map_err(NmeaError::from)?
+        .1;
+    Ok(res)
+}
+
+/// TAG block of NMEA 4.0, like `\s:GPS1,c:1620000000*4C\` before `$`,
+/// gpsd and routers put source, time and grouping of sentence into it
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct TagBlock<'a> {
+    /// Everything between leading `\` and `*`
+    pub data: &'a [u8],
+    pub checksum: u8,
+    /// `s:` source of sentence, like talker or station id
+    pub source: Option<&'a [u8]>,
+    /// `c:` UNIX time in seconds
+    pub unix_time: Option<i64>,
+    /// `g:` grouping of sentences, like `1-2-42` for first of two
+    /// sentences of group 42
+    pub group: Option<&'a [u8]>,
+}
+
+impl<'a> TagBlock<'a> {
+    pub fn calc_checksum(&self) -> u8 {
+        checksum(self.data.iter())
+    }
+}
+
+fn do_parse_tag_block(i: &[u8]) -> IResult<&[u8], (&[u8], u8)> {
+    let (i, data) = preceded(char('\\'), take_until("*"))(i)?;
+    let (i, checksum) = terminated(parse_checksum, char('\\'))(i)?;
+    Ok((i, (data, checksum)))
+}
+
+/// Parse TAG block at start of sentence and verify its checksum,
+/// returns it and the rest of line, which is sentence itself.
+/// Unknown fields are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::parse_tag_block;
+///
+/// let (tag_block, sentence) = parse_tag_block(b"\\s:GPS1,c:1620000000*4C\\$HCHDM,238.5,M*25").unwrap();
+/// assert_eq!(tag_block.source, Some(&b"GPS1"[..]));
+/// assert_eq!(tag_block.unix_time, Some(1_620_000_000));
+/// assert_eq!(sentence, b"$HCHDM,238.5,M*25");
+/// ```
+pub fn parse_tag_block(xs: &[u8]) -> core::result::Result<(TagBlock<'_>, &[u8]), NmeaError> {
+    let (sentence, (data, checksum)) = do_parse_tag_block(xs).map_err(NmeaError::from)?;
+    let mut tag_block = TagBlock {
+        data,
+        checksum,
+        source: None,
+        unix_time: None,
+        group: None,
+    };
+    if tag_block.checksum != tag_block.calc_checksum() {
+        return Err(NmeaError::ChecksumMismatch {
+            expected: tag_block.calc_checksum(),
+            found: tag_block.checksum,
+        });
+    }
+    for field in data.split(|&c| c == b',') {
+        let value = match field {
+            [_, b':', value @ ..] => value,
+            _ => return Err(NmeaError::InvalidField("TAG block field")),
+        };
+        match field[0] {
+            b's' => tag_block.source = Some(value),
+            b'c' => {
+                tag_block.unix_time =
+                    Some(parse_num(value).map_err(|_| NmeaError::InvalidField("TAG block time"))?)
+            }
+            b'g' => tag_block.group = Some(value),
+            _ => {}
+        }
+    }
+    Ok((tag_block, sentence))
+}
+
+/// Sentence without TAG block, if it has one
+fn skip_tag_block(xs: &[u8]) -> core::result::Result<&[u8], NmeaError> {
+    if xs.starts_with(b"\\") {
+        Ok(parse_tag_block(xs)?.1)
+    } else {
+        Ok(xs)
+    }
+}
+
+pub fn parse_nmea_sentence(sentence: &[u8]) -> core::result::Result<NmeaSentence<'_>, NmeaError> {
     /*
      * From gpsd:
      * We've had reports that on the Garmin GPS-10 the device sometimes
@@ -88,27 +471,119 @@ pub fn parse_nmea_sentence(sentence: &[u8]) -> std::result::Result<NmeaSentence,
      * a 100-character PSTI message.
      */
     if sentence.len() > 102 {
-        return Err("Too long message".to_string());
+        return Err(NmeaError::TooLong);
     }
-    let res: NmeaSentence = do_parse_nmea_sentence(sentence)
-        .map_err(|err| match err {
-            nom::Err::Incomplete(_) => "Incomplete nmea sentence".to_string(),
-            nom::Err::Error((_, kind)) | nom::Err::Failure((_, kind)) => {
-                kind.description().to_string()
-            }
-        })?
-        .1;
+    let res: NmeaSentence = do_parse_nmea_sentence(sentence).map_err(NmeaError::from)?.1;
     Ok(res)
 }
 
-fn parse_num<I: std::str::FromStr>(data: &[u8]) -> std::result::Result<I, &'static str> {
-    //    println!("parse num {}", unsafe { str::from_utf8_unchecked(data) });
-    str::parse::<I>(unsafe { str::from_utf8_unchecked(data) }).map_err(|_| "parse of number failed")
+fn parse_num<I: str::FromStr>(data: &[u8]) -> core::result::Result<I, &'static str> {
+    let data = str::from_utf8(data).map_err(|_| "number is not valid utf-8")?;
+    str::parse::<I>(data).map_err(|_| "parse of number failed")
 }
-fn number<T: std::str::FromStr>(i: &[u8]) -> IResult<&[u8], T> {
+#[cfg(any(
+    feature = "gga",
+    feature = "ggk",
+    feature = "gns",
+    feature = "gsa",
+    feature = "gsv",
+    feature = "tll"
+))]
+fn number<T: str::FromStr>(i: &[u8]) -> IResult<&[u8], T> {
     map_res(digit1, parse_num)(i)
 }
 
+/// Check sentence frame and checksum, without parsing of data fields
+///
+/// # Examples
+///
+/// ```
+/// use nmea::is_valid_nmea;
+///
+/// assert!(is_valid_nmea(b"$HCHDM,238.5,M*25"));
+/// assert!(!is_valid_nmea(b"$HCHDM,238.5,M*26"));
+/// ```
+pub fn is_valid_nmea(xs: &[u8]) -> bool {
+    verify_checksum(xs).unwrap_or(false)
+}
+
+/// Compare checksum of sentence with calculated one, only sentence frame
+/// is parsed. Error is returned if frame is broken, for example there is no `*HH`.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::verify_checksum;
+///
+/// assert_eq!(verify_checksum(b"$HCHDM,238.5,M*25"), Ok(true));
+/// assert_eq!(verify_checksum(b"$HCHDM,238.5,M*26"), Ok(false));
+/// assert!(verify_checksum(b"$HCHDM,238.5,M").is_err());
+/// ```
+pub fn verify_checksum(sentence: &[u8]) -> Result<bool, NmeaError> {
+    let s = parse_nmea_sentence(sentence)?;
+    Ok(s.checksum == s.calc_checksum())
+}
+
+/// Talker and type of sentence from its address field only, payload
+/// and checksum are not checked, so sentences can be filtered before
+/// paying for `parse`. TAG block is skipped, for proprietary sentences
+/// `(TalkerId::Proprietary, SentenceType::None)` is returned.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::{classify, SentenceType, TalkerId};
+///
+/// assert_eq!(
+///     classify(b"$GNGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*68"),
+///     Some((TalkerId::GnssCombined, SentenceType::GGA))
+/// );
+/// assert_eq!(classify(b"garbage"), None);
+/// ```
+pub fn classify(bytes: &[u8]) -> Option<(TalkerId, SentenceType)> {
+    let bytes = if bytes.starts_with(b"\\") {
+        let end = bytes[1..].iter().position(|&c| c == b'\\')?;
+        &bytes[end + 2..]
+    } else {
+        bytes
+    };
+    match bytes {
+        [b'$', b'P', ..] => Some((TalkerId::Proprietary, SentenceType::None)),
+        [b'$' | b'!', t1, t2, m1, m2, m3, b',', ..] => Some((
+            TalkerId::from_bytes(&[*t1, *t2]),
+            SentenceType::try_from(&[*m1, *m2, *m3][..]).ok()?,
+        )),
+        _ => None,
+    }
+}
+
+/// Check that buffer has complete frame of sentence: `$` or `!`,
+/// then `*`, two hex digits of checksum and line terminator, so reader
+/// of byte stream knows when to call `parse`. Neither fields
+/// nor checksum are validated.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::is_complete_frame;
+///
+/// assert!(is_complete_frame(b"$HCHDM,238.5,M*25\r\n"));
+/// assert!(!is_complete_frame(b"$HCHDM,238.5,M*2"));
+/// ```
+pub fn is_complete_frame(buf: &[u8]) -> bool {
+    let start = match buf.iter().position(|&c| c == b'$' || c == b'!') {
+        Some(start) => start,
+        None => return false,
+    };
+    buf[start..].windows(4).any(|w| {
+        w[0] == b'*'
+            && w[1].is_ascii_hexdigit()
+            && w[2].is_ascii_hexdigit()
+            && (w[3] == b'\r' || w[3] == b'\n')
+    })
+}
+
+#[cfg(feature = "gsv")]
 fn parse_gsv_sat_info(i: &[u8]) -> IResult<&[u8], Satellite> {
     let (i, prn) = number::<u32>(i)?;
     let (i, _) = char(',')(i)?;
@@ -126,17 +601,35 @@ fn parse_gsv_sat_info(i: &[u8]) -> IResult<&[u8], Satellite> {
             elevation: elevation.map(|v| v as f32),
             azimuth: azimuth.map(|v| v as f32),
             snr: snr.map(|v| v as f32),
+            used_in_fix: false,
         },
     ))
 }
 
+/// Signal id is single hex digit after satellite blocks, so sentence
+/// with it has 3 + 4 * n + 1 fields
+#[cfg(feature = "gsv")]
+fn gsv_signal_id(data: &[u8]) -> Option<u8> {
+    let fields = data.split(|&c| c == b',').count();
+    if fields < 4 || (fields - 3) % 4 != 1 {
+        return None;
+    }
+    match data.rsplit(|&c| c == b',').next()? {
+        [c] => char::from(*c).to_digit(16).map(|id| id as u8),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "gsv")]
 fn do_parse_gsv(i: &[u8]) -> IResult<&[u8], GsvData> {
+    let signal_id = gsv_signal_id(i);
     let (i, number_of_sentences) = number::<u16>(i)?;
     let (i, _) = char(',')(i)?;
     let (i, sentence_num) = number::<u16>(i)?;
     let (i, _) = char(',')(i)?;
-    let (i, _sats_in_view) = number::<u16>(i)?;
-    let (i, _) = char(',')(i)?;
+    let (i, sats_in_view) = number::<u16>(i)?;
+    // no satellite blocks at all if nothing in view
+    let (i, _) = cond(rest_len(i)?.1 > 0, char(','))(i)?;
     let (i, sat0) = opt(parse_gsv_sat_info)(i)?;
     let (i, sat1) = opt(parse_gsv_sat_info)(i)?;
     let (i, sat2) = opt(parse_gsv_sat_info)(i)?;
@@ -147,8 +640,9 @@ fn do_parse_gsv(i: &[u8]) -> IResult<&[u8], GsvData> {
             gnss_type: GnssType::Galileo,
             number_of_sentences,
             sentence_num,
-            _sats_in_view,
+            sats_in_view,
             sats_info: [sat0, sat1, sat2, sat3],
+            signal_id,
         },
     ))
 }
@@ -172,30 +666,30 @@ fn do_parse_gsv(i: &[u8]) -> IResult<&[u8], GsvData> {
 ///   GL (GLONASS),
 ///   GN (GLONASS, any combination GNSS),
 ///   GP (GPS, SBAS, QZSS),
+///   GQ (QZSS),
 ///   QZ (QZSS).
 ///
 /// GL may be (incorrectly) used when GSVs are mixed containing
 /// GLONASS, GN may be (incorrectly) used when GSVs contain GLONASS
 /// only.  Usage is inconsistent.
-pub fn parse_gsv(sentence: &NmeaSentence) -> Result<GsvData, String> {
+#[cfg(feature = "gsv")]
+pub fn parse_gsv(sentence: &NmeaSentence) -> Result<GsvData, NmeaError> {
     if sentence.message_id != b"GSV" {
-        return Err("GSV sentence not starts with $..GSV".into());
+        return Err(NmeaError::unexpected_sentence(
+            SentenceType::GSV,
+            sentence.message_id,
+        ));
     }
     let gnss_type = match sentence.talker_id {
         b"GP" => GnssType::Gps,
         b"GA" => GnssType::Galileo,
         b"GL" | b"GN" => GnssType::Glonass,
-        _ => return Err("Unknown GNSS type in GSV sentence".into()),
+        b"BD" | b"GB" => GnssType::Beidou,
+        b"GQ" | b"QZ" => GnssType::Qzss,
+        _ => return Err(NmeaError::UnknownTalker),
     };
     //    println!("parse: '{}'", str::from_utf8(sentence.data).unwrap());
-    let mut res: GsvData = do_parse_gsv(sentence.data)
-        .map_err(|err| match err {
-            nom::Err::Incomplete(_) => "Incomplete nmea sentence".to_string(),
-            nom::Err::Error((_, kind)) | nom::Err::Failure((_, kind)) => {
-                kind.description().to_string()
-            }
-        })?
-        .1;
+    let mut res: GsvData = do_parse_gsv(sentence.data).map_err(NmeaError::from)?.1;
     res.gnss_type = gnss_type.clone();
     for sat in &mut res.sats_info {
         if let Some(v) = (*sat).as_mut() {
@@ -205,7 +699,8 @@ pub fn parse_gsv(sentence: &NmeaSentence) -> Result<GsvData, String> {
     Ok(res)
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GgaData {
     pub fix_time: Option<NaiveTime>,
     pub fix_type: Option<FixType>,
@@ -215,21 +710,97 @@ pub struct GgaData {
     pub hdop: Option<f32>,
     pub altitude: Option<f32>,
     pub geoid_height: Option<f32>,
+    /// Seconds since last DGPS update, `None` if not reported
+    /// or out of `0..=MAX_DGPS_AGE` range
+    pub age_of_dgps: Option<f32>,
+    /// DGPS reference station, `None` if not reported
+    /// or out of `0..=MAX_DGPS_STATION_ID` range
+    pub dgps_station_id: Option<u16>,
+}
+
+/// Max DGPS station id allowed by standard, bigger value signals corrupted sentence
+pub const MAX_DGPS_STATION_ID: u16 = 1023;
+
+/// Default limit of PRNs kept from one GSA sentence,
+/// as many as the longest known GSA (CH-4701) reports
+pub const MAX_GSA_PRNS: usize = 24;
+
+/// Max age of DGPS corrections in seconds, receivers stop using corrections
+/// much earlier, so older age signals corrupted sentence
+pub const MAX_DGPS_AGE: f32 = 3600.;
+
+/// Longer lines can't be valid sentence, so stream framers drop them
+/// instead of buffering till the next line terminator
+pub(crate) const MAX_LINE_LEN: usize = 1024;
+
+impl GgaData {
+    /// UTC date and time of fix, GGA has only time, so date should
+    /// come from other source, like RMC or ZDA of the same cycle
+    pub fn datetime_with_date(&self, date: NaiveDate) -> Option<DateTime<Utc>> {
+        Some(Utc.from_utc_datetime(&NaiveDateTime::new(date, self.fix_time?)))
+    }
 }
 
-fn parse_float_num<T: str::FromStr>(input: &[u8]) -> std::result::Result<T, &'static str> {
+fn parse_float_num<T: str::FromStr>(input: &[u8]) -> core::result::Result<T, &'static str> {
     let s = str::from_utf8(input).map_err(|_| "invalid float number")?;
     str::parse::<T>(s).map_err(|_| "parse of float number failed")
 }
 
+/// Meters per second in one knot
+const MPS_PER_KNOT: f32 = 0.514_444;
+
+/// Wrap course or heading from sentence into [0, 360),
+/// receivers report `360.0` or `-0.5` because of rounding
+#[cfg(any(feature = "hdm", feature = "rma", feature = "rmc", feature = "vtg"))]
+fn normalize_heading(deg: f32) -> f32 {
+    normalize_degrees(deg)
+}
+
+/// Dilution of precision: `nan` or `inf` from buggy firmware
+/// is treated like empty field
+#[cfg(any(feature = "gga", feature = "ggk", feature = "gns", feature = "gsa"))]
+fn dop(i: &[u8]) -> IResult<&[u8], Option<f32>> {
+    map(
+        opt(map_res(take_while1(|c| c != b','), parse_float_num::<f32>)),
+        |v| v.filter(|x| x.is_finite()),
+    )(i)
+}
+
+/// Seconds of time field should have at least 2 digits before decimal point,
+/// otherwise field is too short, like "1256" or "12561"
+#[cfg(any(
+    feature = "gga",
+    feature = "ggk",
+    feature = "gll",
+    feature = "gns",
+    feature = "gst",
+    feature = "rmc",
+    feature = "tll",
+    feature = "ztg"
+))]
+fn is_valid_seconds(sec: &[u8]) -> bool {
+    let int_part = sec.split(|&c| c == b'.').next().unwrap_or(sec);
+    int_part.len() >= 2 && int_part.iter().all(u8::is_ascii_digit)
+}
+
+#[cfg(any(
+    feature = "gga",
+    feature = "ggk",
+    feature = "gll",
+    feature = "gns",
+    feature = "gst",
+    feature = "rmc",
+    feature = "tll",
+    feature = "ztg"
+))]
 fn parse_hms(i: &[u8]) -> IResult<&[u8], NaiveTime> {
     map_res(
         tuple((
             map_res(take(2usize), parse_num::<u32>),
             map_res(take(2usize), parse_num::<u32>),
-            map_parser(take_until(","), double),
+            map_parser(verify(take_until(","), is_valid_seconds), double),
         )),
-        |(hour, minutes, sec)| -> std::result::Result<NaiveTime, &'static str> {
+        |(hour, minutes, sec)| -> core::result::Result<NaiveTime, &'static str> {
             if sec.is_sign_negative() {
                 return Err("Invalid time: second is negative");
             }
@@ -239,43 +810,103 @@ fn parse_hms(i: &[u8]) -> IResult<&[u8], NaiveTime> {
             if minutes >= 60 {
                 return Err("Invalid time: min >= 60");
             }
-            Ok(NaiveTime::from_hms_nano(
+            NaiveTime::from_hms_nano_opt(
                 hour,
                 minutes,
-                sec.trunc() as u32,
-                (sec.fract() * 1_000_000_000f64).round() as u32,
-            ))
+                sec as u32,
+                ((sec - f64::from(sec as u32)) * 1_000_000_000f64 + 0.5) as u32,
+            )
+            .ok_or("Invalid time")
         },
     )(i)
 }
 
-fn do_parse_lat_lon(i: &[u8]) -> IResult<&[u8], (f64, f64)> {
+/// Parse "DDmm.mm,N,DDDmm.mm,E" pair.
+///
+/// Some receivers leave the hemisphere field empty while still
+/// reporting coordinates (usually when fix is invalid), in this case
+/// the positive hemisphere (N or E) is assumed instead of failing
+/// the whole sentence.
+///
+/// Zero latitude/longitude is always reported as positive zero,
+/// so "0000.000,S" gives the same value as "0000.000,N".
+#[cfg(any(
+    feature = "gga",
+    feature = "ggk",
+    feature = "gll",
+    feature = "gns",
+    feature = "rma",
+    feature = "rmc",
+    feature = "tll"
+))]
+pub(crate) fn do_parse_lat_lon(i: &[u8]) -> IResult<&[u8], (f64, f64)> {
     let (i, lat_deg) = map_res(take(2usize), parse_num::<u8>)(i)?;
     let (i, lat_min) = double(i)?;
     let (i, _) = char(',')(i)?;
-    let (i, lat_dir) = one_of("NS")(i)?;
+    let (i, lat_dir) = opt(one_of("NS"))(i)?;
     let (i, _) = char(',')(i)?;
-    let (i, lon_deg) = map_res(take(3usize), parse_num::<u8>)(i)?;
+    let (i, lon_deg) = map_res(take(3usize), parse_num::<u16>)(i)?;
     let (i, lon_min) = double(i)?;
     let (i, _) = char(',')(i)?;
-    let (i, lon_dir) = one_of("EW")(i)?;
+    let (i, lon_dir) = opt(one_of("EW"))(i)?;
 
     let mut lat = f64::from(lat_deg) + lat_min / 60.;
-    if lat_dir == 'S' {
+    if lat_dir == Some('S') && lat != 0. {
         lat = -lat;
     }
     let mut lon = f64::from(lon_deg) + lon_min / 60.;
-    if lon_dir == 'W' {
+    if lon_dir == Some('W') && lon != 0. {
         lon = -lon;
     }
 
     Ok((i, (lat, lon)))
 }
 
+#[cfg(any(
+    feature = "gga",
+    feature = "ggk",
+    feature = "gns",
+    feature = "rma",
+    feature = "rmc",
+    feature = "tll"
+))]
 fn parse_lat_lon(i: &[u8]) -> IResult<&[u8], Option<(f64, f64)>> {
     alt((map(tag(",,,"), |_| None), map(do_parse_lat_lon, Some)))(i)
 }
 
+/// Check that signed latitude and longitude are in `[-90, 90]`
+/// and `[-180, 180]`, grammar of fields allows up to 99 degrees
+/// and 99.99 minutes for latitude
+#[cfg(any(
+    feature = "gga",
+    feature = "ggk",
+    feature = "gll",
+    feature = "gns",
+    feature = "rma",
+    feature = "rmc",
+    feature = "tll"
+))]
+fn check_lat_lon(lat: Option<f64>, lon: Option<f64>) -> Result<(), NmeaError> {
+    if lat.is_some_and(|lat| !(-90. ..=90.).contains(&lat)) {
+        return Err(NmeaError::InvalidLatitude);
+    }
+    if lon.is_some_and(|lon| !(-180. ..=180.).contains(&lon)) {
+        return Err(NmeaError::InvalidLongitude);
+    }
+    Ok(())
+}
+
+/// Altitude or geoid separation, empty field is `None` and
+/// following `M` unit marker is left for caller, so `,,M,` is not misparsed
+#[cfg(feature = "gga")]
+fn height(i: &[u8]) -> IResult<&[u8], Option<f32>> {
+    alt((
+        value(None, peek(char(','))),
+        map(map_res(take_until(","), parse_float_num::<f32>), Some),
+    ))(i)
+}
+
+#[cfg(feature = "gga")]
 fn do_parse_gga(i: &[u8]) -> IResult<&[u8], GgaData> {
     let (i, fix_time) = opt(parse_hms)(i)?;
     let (i, _) = char(',')(i)?;
@@ -285,15 +916,17 @@ fn do_parse_gga(i: &[u8]) -> IResult<&[u8], GgaData> {
     let (i, _) = char(',')(i)?;
     let (i, fix_satellites) = opt(number::<u32>)(i)?;
     let (i, _) = char(',')(i)?;
-    let (i, hdop) = opt(float)(i)?;
+    let (i, hdop) = dop(i)?;
     let (i, _) = char(',')(i)?;
-    let (i, altitude) = opt(map_res(take_until(","), parse_float_num::<f32>))(i)?;
+    let (i, altitude) = height(i)?;
     let (i, _) = char(',')(i)?;
     let (i, _) = opt(char('M'))(i)?;
     let (i, _) = char(',')(i)?;
-    let (i, geoid_height) = opt(map_res(take_until(","), parse_float_num::<f32>))(i)?;
+    let (i, geoid_height) = height(i)?;
     let (i, _) = char(',')(i)?;
     let (i, _) = opt(char('M'))(i)?;
+    let (i, age_of_dgps) = opt(preceded(char(','), opt(float)))(i)?;
+    let (i, dgps_station_id) = opt(preceded(char(','), opt(number::<u32>)))(i)?;
 
     Ok((
         i,
@@ -306,6 +939,13 @@ fn do_parse_gga(i: &[u8]) -> IResult<&[u8], GgaData> {
             hdop,
             altitude,
             geoid_height,
+            age_of_dgps: age_of_dgps
+                .flatten()
+                .filter(|age| (0. ..=MAX_DGPS_AGE).contains(age)),
+            dgps_station_id: dgps_station_id
+                .flatten()
+                .filter(|&id| id <= u32::from(MAX_DGPS_STATION_ID))
+                .map(|id| id as u16),
         },
     ))
 }
@@ -327,29 +967,32 @@ fn do_parse_gga(i: &[u8]) -> IResult<&[u8], GgaData> {
 /// ellipsoid, in Meters
 /// (empty field) time in seconds since last DGPS update
 /// (empty field) DGPS station ID number (0000-1023)
-pub fn parse_gga(sentence: &NmeaSentence) -> Result<GgaData, String> {
+///
+/// Out of range DGPS age or station ID are reported as `None`,
+/// like they are not provided.
+#[cfg(feature = "gga")]
+pub fn parse_gga(sentence: &NmeaSentence) -> Result<GgaData, NmeaError> {
     if sentence.message_id != b"GGA" {
-        return Err("GGA sentence not starts with $..GGA".into());
+        return Err(NmeaError::unexpected_sentence(
+            SentenceType::GGA,
+            sentence.message_id,
+        ));
     }
-    let res: GgaData = do_parse_gga(sentence.data)
-        .map_err(|err| match err {
-            nom::Err::Incomplete(_) => "Incomplete nmea sentence".to_string(),
-            nom::Err::Error((_, kind)) | nom::Err::Failure((_, kind)) => {
-                kind.description().to_string()
-            }
-        })?
-        .1;
+    let res: GgaData = do_parse_gga(sentence.data).map_err(NmeaError::from)?.1;
+    check_lat_lon(res.latitude, res.longitude)?;
     Ok(res)
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RmcStatusOfFix {
     Autonomous,
     Differential,
     Invalid,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RmcData {
     pub fix_time: Option<NaiveTime>,
     pub fix_date: Option<NaiveDate>,
@@ -358,8 +1001,53 @@ pub struct RmcData {
     pub lon: Option<f64>,
     pub speed_over_ground: Option<f32>,
     pub true_course: Option<f32>,
+    /// Magnetic variation in degrees, east is positive, west is negative
+    pub magnetic_variation: Option<f32>,
+    pub nav_status: Option<char>,
+}
+
+impl RmcData {
+    /// Course relative to magnetic north in range [0, 360),
+    /// if both true course and magnetic variation are known
+    pub fn magnetic_course(&self) -> Option<f32> {
+        Some(normalize_degrees(
+            self.true_course? - self.magnetic_variation?,
+        ))
+    }
+
+    /// Speed over ground in meters per second
+    pub fn speed_over_ground_mps(&self) -> Option<f32> {
+        self.speed_over_ground.map(|v| v * MPS_PER_KNOT)
+    }
+
+    /// UTC date and time of fix, if both are present
+    pub fn datetime(&self) -> Option<NaiveDateTime> {
+        Some(NaiveDateTime::new(self.fix_date?, self.fix_time?))
+    }
+
+    /// Same as `datetime`, but as timezone aware `DateTime`
+    pub fn datetime_utc(&self) -> Option<DateTime<Utc>> {
+        self.datetime().map(|utc| Utc.from_utc_datetime(&utc))
+    }
+
+    /// Date and time of fix converted to `tz` timezone
+    pub fn local_datetime<Tz: TimeZone>(&self, tz: &Tz) -> Option<DateTime<Tz>> {
+        self.datetime_utc().map(|utc| utc.with_timezone(tz))
+    }
+}
+
+/// Full year of 2-digit `yy` field, in 1980-2079 window
+/// (GPS time starts in 1980)
+#[cfg(any(feature = "ggk", feature = "rmc"))]
+fn full_year(yy: u8) -> i32 {
+    if yy < 80 {
+        2000 + i32::from(yy)
+    } else {
+        1900 + i32::from(yy)
+    }
 }
 
+#[cfg(feature = "rmc")]
 fn parse_date(i: &[u8]) -> IResult<&[u8], NaiveDate> {
     map_res(
         tuple((
@@ -368,36 +1056,53 @@ fn parse_date(i: &[u8]) -> IResult<&[u8], NaiveDate> {
             map_res(take(2usize), parse_num::<u8>),
         )),
         |data| -> Result<NaiveDate, &'static str> {
-            let (day, month, year) = (u32::from(data.0), u32::from(data.1), i32::from(data.2));
-            if month < 1 || month > 12 {
+            let (day, month, year) = (u32::from(data.0), u32::from(data.1), full_year(data.2));
+            if !(1..=12).contains(&month) {
                 return Err("Invalid month < 1 or > 12");
             }
-            if day < 1 || day > 31 {
+            if !(1..=31).contains(&day) {
                 return Err("Invalid day < 1 or > 31");
             }
-            Ok(NaiveDate::from_ymd(year, month, day))
+            NaiveDate::from_ymd_opt(year, month, day).ok_or("Invalid date")
         },
     )(i)
 }
 
+/// Fields after date: magnetic variation with its direction, FAA mode (NMEA 2.3)
+/// and navigational status (NMEA 4.1), returns signed variation and status
+#[cfg(feature = "rmc")]
+fn do_parse_rmc_tail(i: &[u8]) -> IResult<&[u8], (Option<f32>, Option<char>)> {
+    let (i, variation) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, variation_dir) = opt(one_of("EW"))(i)?;
+    let (i, _) = opt(preceded(char(','), opt(one_of("ADEFMNPRS"))))(i)?;
+    let (i, nav_status) = opt(preceded(char(','), opt(one_of("SCUV"))))(i)?;
+    let variation = variation.map(|v| if variation_dir == Some('W') { -v } else { v });
+    Ok((i, (variation, nav_status.flatten())))
+}
+
+#[cfg(feature = "rmc")]
 fn do_parse_rmc(i: &[u8]) -> IResult<&[u8], RmcData> {
     map_res(
         tuple((
             terminated(opt(parse_hms), char(',')),
             terminated(one_of("ADV"), char(',')),
             terminated(parse_lat_lon, char(',')),
-            terminated(
-                opt(float),
-                char(','),
-            ),
-            terminated(
-                opt(float),
-                char(','),
-            ),
+            terminated(opt(float), char(',')),
+            terminated(opt(float), char(',')),
             terminated(opt(parse_date), char(',')),
+            opt(do_parse_rmc_tail),
         )),
-        |(fix_time, status_of_fix, lat_lon, speed_over_ground, true_course, fix_date)|
-                -> Result<RmcData, &'static str> {
+        |(
+            fix_time,
+            status_of_fix,
+            lat_lon,
+            speed_over_ground,
+            true_course,
+            fix_date,
+            tail,
+        )|
+         -> Result<RmcData, &'static str> {
             Ok(RmcData {
                 fix_time,
                 fix_date,
@@ -410,7 +1115,9 @@ fn do_parse_rmc(i: &[u8]) -> IResult<&[u8], RmcData> {
                 lat: lat_lon.map(|v| v.0),
                 lon: lat_lon.map(|v| v.1),
                 speed_over_ground,
-                true_course,
+                true_course: true_course.map(normalize_heading),
+                magnetic_variation: tail.and_then(|v| v.0),
+                nav_status: tail.and_then(|v| v.1),
             })
         },
     )(i)
@@ -431,38 +1138,47 @@ fn do_parse_rmc(i: &[u8]) -> IResult<&[u8], RmcData> {
 /// 12    A      FAA mode indicator (NMEA 2.3 and later)
 /// A=autonomous, D=differential, E=Estimated,
 /// N=not valid, S=Simulator, M=Manual input mode
+/// 13    S      Navigational status (NMEA 4.1 and later)
+/// S=safe, C=caution, U=unsafe, V=not valid
 /// *68        mandatory nmea_checksum
 ///
 /// SiRF chipsets don't return either Mode Indicator or magnetic variation.
-pub fn parse_rmc(sentence: &NmeaSentence) -> Result<RmcData, String> {
+#[cfg(feature = "rmc")]
+pub fn parse_rmc(sentence: &NmeaSentence) -> Result<RmcData, NmeaError> {
     if sentence.message_id != b"RMC" {
-        return Err("RMC message should starts with $..RMC".into());
+        return Err(NmeaError::unexpected_sentence(
+            SentenceType::RMC,
+            sentence.message_id,
+        ));
     }
-    do_parse_rmc(sentence.data)
+    let res = do_parse_rmc(sentence.data)
         .map(|(_, data)| data)
-        .map_err(|err| match err {
-            nom::Err::Incomplete(_) => "Incomplete nmea sentence".to_string(),
-            nom::Err::Error((_, kind)) | nom::Err::Failure((_, kind)) => {
-                kind.description().to_string()
-            }
-        })
+        .map_err(NmeaError::from)?;
+    check_lat_lon(res.lat, res.lon)?;
+    Ok(res)
 }
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GsaMode1 {
     Manual,
     Automatic,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GsaMode2 {
     NoFix,
     Fix2D,
     Fix3D,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GsaData {
+    /// Constellation of satellites in sentence, None for
+    /// GN (combined GNSS) or unknown talker
+    pub gnss_type: Option<GnssType>,
     pub mode1: GsaMode1,
     pub mode2: GsaMode2,
     pub fix_sats_prn: Vec<u32>,
@@ -471,54 +1187,125 @@ pub struct GsaData {
     pub vdop: Option<f32>,
 }
 
-fn gsa_prn_fields_parse(i: &[u8]) -> IResult<&[u8], Vec<Option<u32>>> {
-    many0(terminated(opt(number::<u32>), char(',')))(i)
+/// Empty GSA: automatic mode without fix
+impl Default for GsaData {
+    fn default() -> Self {
+        GsaData {
+            gnss_type: None,
+            mode1: GsaMode1::Automatic,
+            mode2: GsaMode2::NoFix,
+            fix_sats_prn: Vec::new(),
+            pdop: None,
+            hdop: None,
+            vdop: None,
+        }
+    }
+}
+
+impl GsaData {
+    /// Number of satellites used in fix
+    pub fn satellites_used(&self) -> usize {
+        self.fix_sats_prn.len()
+    }
+
+    /// PRNs used in fix with their constellation, `gnss_type` of sentence
+    /// if known, otherwise guessed from PRN range with `prn_to_gnss`
+    pub fn prns_with_gnss(&self) -> Vec<(u32, Option<GnssType>)> {
+        self.fix_sats_prn
+            .iter()
+            .map(|&prn| (prn, self.gnss_type.clone().or_else(|| prn_to_gnss(prn))))
+            .collect()
+    }
+}
+
+/// Unique PRNs of GSA, fields after first `max` PRNs are skipped,
+/// so memory doesn't grow with length of malicious sentence
+#[cfg(feature = "gsa")]
+fn gsa_prn_fields_parse(i: &[u8], max: usize) -> IResult<&[u8], Vec<u32>> {
+    // `many0` requires nom's "alloc" feature, which is nightly only without "std"
+    fold_many0(
+        terminated(opt(number::<u32>), char(',')),
+        Vec::new(),
+        move |mut acc: Vec<u32>, prn| {
+            if let Some(prn) = prn {
+                if acc.len() < max && !acc.contains(&prn) {
+                    acc.push(prn);
+                }
+            }
+            acc
+        },
+    )(i)
 }
 
-type GsaTail = (Vec<Option<u32>>, Option<f32>, Option<f32>, Option<f32>);
+#[cfg(feature = "gsa")]
+type GsaTail = (
+    Vec<u32>,
+    Option<f32>,
+    Option<f32>,
+    Option<f32>,
+    Option<GnssType>,
+);
+
+/// NMEA 4.1 GNSS system id
+#[cfg(feature = "gsa")]
+fn gsa_system_id(i: &[u8]) -> IResult<&[u8], Option<GnssType>> {
+    map(one_of("123456"), |id| match id {
+        '1' => Some(GnssType::Gps),
+        '2' => Some(GnssType::Glonass),
+        '3' => Some(GnssType::Galileo),
+        '4' => Some(GnssType::Beidou),
+        '5' => Some(GnssType::Qzss),
+        // NavIC
+        _ => None,
+    })(i)
+}
 
-fn do_parse_gsa_tail(i: &[u8]) -> IResult<&[u8], GsaTail> {
-    let (i, prns) = gsa_prn_fields_parse(i)?;
-    let (i, pdop) = float(i)?;
+#[cfg(feature = "gsa")]
+fn do_parse_gsa_tail(i: &[u8], max_prns: usize) -> IResult<&[u8], GsaTail> {
+    let (i, prns) = gsa_prn_fields_parse(i, max_prns)?;
+    let (i, pdop) = dop(i)?;
     let (i, _) = char(',')(i)?;
-    let (i, hdop) = float(i)?;
+    let (i, hdop) = dop(i)?;
     let (i, _) = char(',')(i)?;
-    let (i, vdop) = float(i)?;
-    Ok((i, (prns, Some(pdop), Some(hdop), Some(vdop))))
+    let (i, vdop) = dop(i)?;
+    let (i, system) = opt(preceded(char(','), gsa_system_id))(i)?;
+    Ok((i, (prns, pdop, hdop, vdop, system.flatten())))
 }
 
+#[cfg(feature = "gsa")]
 fn is_comma(x: u8) -> bool {
     x == b','
 }
 
+#[cfg(feature = "gsa")]
 fn do_parse_empty_gsa_tail(i: &[u8]) -> IResult<&[u8], GsaTail> {
     value(
-        (Vec::new(), None, None, None),
+        (Vec::new(), None, None, None, None),
         all_consuming(take_while1(is_comma)),
     )(i)
 }
 
-fn do_parse_gsa(i: &[u8]) -> IResult<&[u8], GsaData> {
-    let (i, mode1) = one_of("MA")(i)?;
+#[cfg(feature = "gsa")]
+fn do_parse_gsa(i: &[u8], max_prns: usize) -> IResult<&[u8], GsaData> {
+    let (i, mode1) = alt((
+        value(GsaMode1::Manual, char('M')),
+        value(GsaMode1::Automatic, char('A')),
+    ))(i)?;
     let (i, _) = char(',')(i)?;
-    let (i, mode2) = one_of("123")(i)?;
+    let (i, mode2) = opt(alt((
+        value(GsaMode2::NoFix, one_of("01")),
+        value(GsaMode2::Fix2D, char('2')),
+        value(GsaMode2::Fix3D, char('3')),
+    )))(i)?;
     let (i, _) = char(',')(i)?;
-    let (i, mut tail) = alt((do_parse_empty_gsa_tail, do_parse_gsa_tail))(i)?;
+    let (i, tail) = alt((do_parse_empty_gsa_tail, |i| do_parse_gsa_tail(i, max_prns)))(i)?;
     Ok((
         i,
         GsaData {
-            mode1: match mode1 {
-                'M' => GsaMode1::Manual,
-                'A' => GsaMode1::Automatic,
-                _ => unreachable!(),
-            },
-            mode2: match mode2 {
-                '1' => GsaMode2::NoFix,
-                '2' => GsaMode2::Fix2D,
-                '3' => GsaMode2::Fix3D,
-                _ => unreachable!(),
-            },
-            fix_sats_prn: tail.0.drain(..).filter_map(|v| v).collect(),
+            gnss_type: tail.4,
+            mode1,
+            mode2: mode2.unwrap_or(GsaMode2::NoFix),
+            fix_sats_prn: tail.0,
             pdop: tail.1,
             hdop: tail.2,
             vdop: tail.3,
@@ -534,15 +1321,19 @@ fn do_parse_gsa(i: &[u8]) -> IResult<&[u8], GsaData> {
 /// M=Manual, forced to operate in 2D or 3D
 /// A=Automatic, 3D/2D
 /// 2    = Mode: 1=Fix not available, 2=2D, 3=3D
+///        (some receivers report 0 or empty field instead of 1)
 /// 3-14 = PRNs of satellites used in position fix (null for unused fields)
 /// 15   = PDOP
 /// 16   = HDOP
 /// 17   = VDOP
+/// 18   = GNSS System ID (NMEA 4.1 and later): 1=GPS, 2=GLONASS,
+///        3=Galileo, 4=BeiDou, 5=QZSS, 6=NavIC
 ///
 /// Not all documentation specifies the number of PRN fields, it
 /// may be variable.  Most doc that specifies says 12 PRNs.
 ///
-/// the CH-4701 ourputs 24 PRNs!
+/// the CH-4701 ourputs 24 PRNs! So by default only first `MAX_GSA_PRNS`
+/// PRNs are kept, see `ParseOptions::max_gsa_prns`.
 ///
 /// The Skytraq S2525F8-BD-RTK output both GPGSA and BDGSA in the
 /// same cycle:
@@ -566,39 +1357,65 @@ fn do_parse_gsa(i: &[u8]) -> IResult<&[u8], GsaData> {
 /// in at least two ways: it's got the wrong number of fields, and
 /// it claims to be a valid sentence (A flag) when it isn't.
 /// Alarmingly, it's possible this error may be generic to SiRFstarIII
-fn parse_gsa(s: &NmeaSentence) -> Result<GsaData, String> {
+#[cfg(feature = "gsa")]
+fn parse_gsa(s: &NmeaSentence) -> Result<GsaData, NmeaError> {
+    parse_gsa_capped(s, MAX_GSA_PRNS)
+}
+
+#[cfg(feature = "gsa")]
+fn parse_gsa_capped(s: &NmeaSentence, max_prns: usize) -> Result<GsaData, NmeaError> {
     if s.message_id != b"GSA" {
-        return Err("GSA message should starts with $..GSA".into());
+        return Err(NmeaError::unexpected_sentence(
+            SentenceType::GSA,
+            s.message_id,
+        ));
     }
-    let ret: GsaData = do_parse_gsa(s.data)
+    let mut ret: GsaData = do_parse_gsa(s.data, max_prns)
         .map(|(_, data)| data)
-        .map_err(|err| match err {
-            nom::Err::Incomplete(_) => "Incomplete nmea sentence".to_string(),
-            nom::Err::Error((_, kind)) | nom::Err::Failure((_, kind)) => {
-                kind.description().to_string()
-            }
-        })?;
+        .map_err(NmeaError::from)?;
+    if ret.gnss_type.is_none() {
+        ret.gnss_type = match s.talker_id {
+            b"GP" => Some(GnssType::Gps),
+            b"GL" => Some(GnssType::Glonass),
+            b"GA" => Some(GnssType::Galileo),
+            b"BD" | b"GB" => Some(GnssType::Beidou),
+            b"GQ" | b"QZ" => Some(GnssType::Qzss),
+            _ => None,
+        };
+    }
     Ok(ret)
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VtgData {
     pub true_course: Option<f32>,
+    /// Course relative to magnetic north
+    pub magnetic_course: Option<f32>,
     pub speed_over_ground: Option<f32>,
 }
 
+impl VtgData {
+    /// Speed over ground in meters per second
+    pub fn speed_over_ground_mps(&self) -> Option<f32> {
+        self.speed_over_ground.map(|v| v * MPS_PER_KNOT)
+    }
+}
+
+#[cfg(feature = "vtg")]
 fn do_parse_vtg(i: &[u8]) -> IResult<&[u8], VtgData> {
     let (i, true_course) = opt(float)(i)?;
     let (i, _) = char(',')(i)?;
     let (i, _) = opt(char('T'))(i)?;
     let (i, _) = char(',')(i)?;
-    let (i, _magn_course) = opt(float)(i)?;
+    let (i, magnetic_course) = opt(float)(i)?;
     let (i, _) = char(',')(i)?;
     let (i, _) = opt(char('M'))(i)?;
     let (i, _) = char(',')(i)?;
     let (i, knots_ground_speed) = opt(float)(i)?;
     let (i, _) = char(',')(i)?;
     let (i, _) = opt(char('N'))(i)?;
+    let (i, _) = char(',')(i)?;
     let (i, kph_ground_speed) = opt(float)(i)?;
     let (i, _) = char(',')(i)?;
     let (i, _) = opt(char('K'))(i)?;
@@ -606,7 +1423,8 @@ fn do_parse_vtg(i: &[u8]) -> IResult<&[u8], VtgData> {
     Ok((
         i,
         VtgData {
-            true_course,
+            true_course: true_course.map(normalize_heading),
+            magnetic_course: magnetic_course.map(normalize_heading),
             speed_over_ground: match (knots_ground_speed, kph_ground_speed) {
                 (Some(val), _) => Some(val),
                 (_, Some(val)) => Some(val / 1.852),
@@ -647,18 +1465,17 @@ fn do_parse_vtg(i: &[u8]) -> IResult<&[u8], VtgData> {
 /// x.x,M = Track, degrees Magnetic
 /// x.x,N = Speed, knots
 /// x.x,K = Speed, Km/hr
-fn parse_vtg(s: &NmeaSentence) -> Result<VtgData, String> {
+#[cfg(feature = "vtg")]
+fn parse_vtg(s: &NmeaSentence) -> Result<VtgData, NmeaError> {
     if s.message_id != b"VTG" {
-        return Err("VTG message should starts with $..VTG".into());
+        return Err(NmeaError::unexpected_sentence(
+            SentenceType::VTG,
+            s.message_id,
+        ));
     }
     let ret: VtgData = do_parse_vtg(s.data)
         .map(|(_, data)| data)
-        .map_err(|err| match err {
-            nom::Err::Incomplete(_) => "Incomplete nmea sentence".to_string(),
-            nom::Err::Error((_, kind)) | nom::Err::Failure((_, kind)) => {
-                kind.description().to_string()
-            }
-        })?;
+        .map_err(NmeaError::from)?;
     Ok(ret)
 }
 
@@ -676,23 +1493,24 @@ fn parse_vtg(s: &NmeaSentence) -> Result<VtgData, String> {
 /// | 7     | data status | Data status: A = Data valid, V = Data invalid
 /// | 8     | mode ind    | Positioning system mode indicator, see `PosSystemIndicator`
 /// | 9     | *xx         | Check sum
-fn parse_gll(s: &NmeaSentence) -> Result<GllData, String> {
+#[cfg(feature = "gll")]
+fn parse_gll(s: &NmeaSentence) -> Result<GllData, NmeaError> {
     if s.message_id != b"GLL" {
-        return Err("GLL message should starts with $..GLL".into());
+        return Err(NmeaError::unexpected_sentence(
+            SentenceType::GLL,
+            s.message_id,
+        ));
     }
     let ret = do_parse_gll(s.data)
         .map(|(_, data)| data)
-        .map_err(|err| match err {
-            nom::Err::Incomplete(_) => "Incomplete nmea sentence".to_string(),
-            nom::Err::Error((_, kind)) | nom::Err::Failure((_, kind)) => {
-                kind.description().to_string()
-            }
-        })?;
+        .map_err(NmeaError::from)?;
+    check_lat_lon(Some(ret.latitude), Some(ret.longitude))?;
     Ok(ret)
 }
 
 /// Positioning System Mode Indicator (present from NMEA >= 2.3)
 #[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PosSystemIndicator {
     Autonomous,
     Differential,
@@ -715,6 +1533,7 @@ impl From<char> for PosSystemIndicator {
 }
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GllData {
     pub latitude: f64,
     pub longitude: f64,
@@ -722,6 +1541,20 @@ pub struct GllData {
     pub mode: Option<PosSystemIndicator>,
 }
 
+/// Zero coordinates at midnight, position of GLL is always present,
+/// so there is no empty value for it
+impl Default for GllData {
+    fn default() -> Self {
+        GllData {
+            latitude: 0.,
+            longitude: 0.,
+            fix_time: NaiveTime::from_hms_opt(0, 0, 0).unwrap(),
+            mode: None,
+        }
+    }
+}
+
+#[cfg(feature = "gll")]
 fn do_parse_gll(i: &[u8]) -> IResult<&[u8], GllData> {
     let (i, (latitude, longitude)) = do_parse_lat_lon(i)?;
     let (i, _) = char(',')(i)?;
@@ -732,7 +1565,7 @@ fn do_parse_gll(i: &[u8]) -> IResult<&[u8], GllData> {
     let (i, _) = char(',')(i)?;
     let (i, mode) = opt(terminated(
         map(one_of("ADEM"), PosSystemIndicator::from), // ignore 'N' for invalid
-        char(','),
+        opt(char(',')),
     ))(i)?;
 
     Ok((
@@ -746,90 +1579,2255 @@ fn do_parse_gll(i: &[u8]) -> IResult<&[u8], GllData> {
     ))
 }
 
-pub enum ParseResult {
-    GGA(GgaData),
-    RMC(RmcData),
-    GSV(GsvData),
-    GSA(GsaData),
-    VTG(VtgData),
-    GLL(GllData),
-    Unsupported(SentenceType),
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VdrData {
+    pub direction_true: Option<f32>,
+    pub direction_magnetic: Option<f32>,
+    pub drift_knots: Option<f32>,
 }
 
-/// parse nmea 0183 sentence and extract data from it
-pub fn parse(xs: &[u8]) -> Result<ParseResult, String> {
-    let nmea_sentence = parse_nmea_sentence(xs)?;
+#[cfg(feature = "vdr")]
+fn do_parse_vdr(i: &[u8]) -> IResult<&[u8], VdrData> {
+    let (i, direction_true) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('T'))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, direction_magnetic) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('M'))(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, drift_knots) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('N'))(i)?;
 
-    if nmea_sentence.checksum == nmea_sentence.calc_checksum() {
-        match SentenceType::try_from(nmea_sentence.message_id)? {
-            SentenceType::GGA => {
-                let data = parse_gga(&nmea_sentence)?;
-                Ok(ParseResult::GGA(data))
-            }
-            SentenceType::GSV => {
-                let data = parse_gsv(&nmea_sentence)?;
-                Ok(ParseResult::GSV(data))
-            }
-            SentenceType::RMC => {
-                let data = parse_rmc(&nmea_sentence)?;
-                Ok(ParseResult::RMC(data))
-            }
-            SentenceType::GSA => Ok(ParseResult::GSA(parse_gsa(&nmea_sentence)?)),
-            SentenceType::VTG => Ok(ParseResult::VTG(parse_vtg(&nmea_sentence)?)),
-            SentenceType::GLL => Ok(ParseResult::GLL(parse_gll(&nmea_sentence)?)),
-            msg_id => Ok(ParseResult::Unsupported(msg_id)),
-        }
-    } else {
-        Err("Checksum mismatch".into())
+    Ok((
+        i,
+        VdrData {
+            direction_true,
+            direction_magnetic,
+            drift_knots,
+        },
+    ))
+}
+
+/// Parse VDR (Set and Drift)
+/// from gpsd:
+/// $--VDR,x.x,T,x.x,M,x.x,N*hh
+/// 1,2 x.x,T  Direction (set), degrees True
+/// 3,4 x.x,M  Direction (set), degrees Magnetic
+/// 5,6 x.x,N  Current speed (drift), knots
+#[cfg(feature = "vdr")]
+fn parse_vdr(s: &NmeaSentence) -> Result<VdrData, NmeaError> {
+    if s.message_id != b"VDR" {
+        return Err(NmeaError::unexpected_sentence(
+            SentenceType::VDR,
+            s.message_id,
+        ));
     }
+    let ret: VdrData = do_parse_vdr(s.data)
+        .map(|(_, data)| data)
+        .map_err(NmeaError::from)?;
+    Ok(ret)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use approx::relative_eq;
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HdmData {
+    pub heading: Option<f32>,
+}
 
-    #[test]
-    fn test_do_parse_lat_lon() {
+#[cfg(feature = "hdm")]
+fn do_parse_hdm(i: &[u8]) -> IResult<&[u8], HdmData> {
+    let (i, heading) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, _) = opt(char('M'))(i)?;
+
+    Ok((
+        i,
+        HdmData {
+            heading: heading.map(normalize_heading),
+        },
+    ))
+}
+
+/// Parse HDM (Heading - Magnetic)
+/// from gpsd:
+/// $--HDM,x.x,M*hh
+/// 1,2 x.x,M  Heading, degrees Magnetic
+///
+/// Unlike HDG there is no deviation or variation fields.
+#[cfg(feature = "hdm")]
+fn parse_hdm(s: &NmeaSentence) -> Result<HdmData, NmeaError> {
+    if s.message_id != b"HDM" {
+        return Err(NmeaError::unexpected_sentence(
+            SentenceType::HDM,
+            s.message_id,
+        ));
+    }
+    let ret: HdmData = do_parse_hdm(s.data)
+        .map(|(_, data)| data)
+        .map_err(NmeaError::from)?;
+    Ok(ret)
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GnsData {
+    pub fix_time: Option<NaiveTime>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    /// Mode indicator, one char per constellation in order
+    /// GPS, GLONASS, Galileo, BeiDou, QZSS, NavIC
+    pub mode_indicator: String,
+    pub fix_satellites: Option<u32>,
+    pub hdop: Option<f32>,
+    pub altitude: Option<f32>,
+    pub geoid_height: Option<f32>,
+    /// Age of differential data, seconds
+    pub age_of_differential: Option<f32>,
+    pub differential_station_id: Option<u16>,
+    /// Navigational status (NMEA 4.1 and later)
+    pub nav_status: Option<char>,
+}
+
+#[cfg(feature = "gns")]
+fn do_parse_gns(i: &[u8]) -> IResult<&[u8], GnsData> {
+    let (i, fix_time) = opt(parse_hms)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, lat_lon) = parse_lat_lon(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, mode_indicator) =
+        map_res(take_while(|c| b"ADEFMNPRS".contains(&c)), str::from_utf8)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, fix_satellites) = opt(number::<u32>)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, hdop) = dop(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, altitude) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, geoid_height) = opt(float)(i)?;
+    // short form ends here
+    let (i, age_of_differential) = opt(preceded(char(','), opt(float)))(i)?;
+    let (i, differential_station_id) = opt(preceded(char(','), opt(number::<u16>)))(i)?;
+    let (i, nav_status) = opt(preceded(char(','), opt(one_of("SCUV"))))(i)?;
+
+    Ok((
+        i,
+        GnsData {
+            fix_time,
+            latitude: lat_lon.map(|v| v.0),
+            longitude: lat_lon.map(|v| v.1),
+            mode_indicator: mode_indicator.to_string(),
+            fix_satellites,
+            hdop,
+            altitude,
+            geoid_height,
+            age_of_differential: age_of_differential.flatten(),
+            differential_station_id: differential_station_id.flatten(),
+            nav_status: nav_status.flatten(),
+        },
+    ))
+}
+
+/// Parse GNS (GNSS Fix Data)
+/// from gpsd:
+/// $--GNS,hhmmss.ss,llll.ll,a,yyyyy.yy,a,c--c,xx,x.x,x.x,x.x,x.x,x.x,a*hh
+/// 1     hhmmss.ss  UTC
+/// 2,3   llll.ll,a  Latitude, N/S
+/// 4,5   yyyyy.yy,a Longitude, E/W
+/// 6     c--c       Mode indicator, one char per constellation
+/// N=no fix, A=autonomous, D=differential, P=precise, R=RTK
+/// F=float RTK, E=estimated, M=manual, S=simulator
+/// 7     xx         Number of satellites in use
+/// 8     x.x        HDOP
+/// 9     x.x        Altitude, meters above mean sea level
+/// 10    x.x        Geoidal separation, meters
+/// 11    x.x        Age of differential data
+/// 12    x.x        Differential reference station ID
+/// 13    a          Navigational status (NMEA 4.1 and later)
+/// S=safe, C=caution, U=unsafe, V=not valid
+///
+/// Older receivers omit fields 11-13 entirely.
+#[cfg(feature = "gns")]
+fn parse_gns(s: &NmeaSentence) -> Result<GnsData, NmeaError> {
+    if s.message_id != b"GNS" {
+        return Err(NmeaError::unexpected_sentence(
+            SentenceType::GNS,
+            s.message_id,
+        ));
+    }
+    let ret: GnsData = do_parse_gns(s.data)
+        .map(|(_, data)| data)
+        .map_err(NmeaError::from)?;
+    check_lat_lon(ret.latitude, ret.longitude)?;
+    Ok(ret)
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZtgData {
+    pub utc: Option<NaiveTime>,
+    pub time_to_go: Option<NaiveTime>,
+    pub waypoint_id: String,
+}
+
+#[cfg(feature = "ztg")]
+fn do_parse_ztg(i: &[u8]) -> IResult<&[u8], ZtgData> {
+    let (i, utc) = opt(parse_hms)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, time_to_go) = opt(parse_hms)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, waypoint_id) = map_res(rest, str::from_utf8)(i)?;
+
+    Ok((
+        i,
+        ZtgData {
+            utc,
+            time_to_go,
+            waypoint_id: waypoint_id.to_string(),
+        },
+    ))
+}
+
+/// Parse ZTG (UTC & Time to Destination Waypoint)
+/// from gpsd:
+/// $--ZTG,hhmmss.ss,hhmmss.ss,c--c*hh
+/// 1 hhmmss.ss  UTC of observation
+/// 2 hhmmss.ss  Time Remaining
+/// 3 c--c       Destination Waypoint ID
+#[cfg(feature = "ztg")]
+fn parse_ztg(s: &NmeaSentence) -> Result<ZtgData, NmeaError> {
+    if s.message_id != b"ZTG" {
+        return Err(NmeaError::unexpected_sentence(
+            SentenceType::ZTG,
+            s.message_id,
+        ));
+    }
+    let ret: ZtgData = do_parse_ztg(s.data)
+        .map(|(_, data)| data)
+        .map_err(NmeaError::from)?;
+    Ok(ret)
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RmaData {
+    /// `false` if receiver reports warning (`V`) status
+    pub data_valid: bool,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    /// Loran-C time difference A, microseconds
+    pub time_difference_a: Option<f32>,
+    /// Loran-C time difference B, microseconds
+    pub time_difference_b: Option<f32>,
+    pub speed_over_ground: Option<f32>,
+    pub true_course: Option<f32>,
+    /// Magnetic variation in degrees, east is positive, west is negative
+    pub magnetic_variation: Option<f32>,
+}
+
+#[cfg(feature = "rma")]
+fn do_parse_rma(i: &[u8]) -> IResult<&[u8], RmaData> {
+    let (i, status) = one_of("AV")(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, lat_lon) = parse_lat_lon(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, time_difference_a) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, time_difference_b) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, speed_over_ground) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, true_course) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, variation) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, variation_dir) = opt(one_of("EW"))(i)?;
+    let (i, _) = opt(preceded(char(','), opt(one_of("ADEMNS"))))(i)?;
+
+    Ok((
+        i,
+        RmaData {
+            data_valid: status == 'A',
+            latitude: lat_lon.map(|v| v.0),
+            longitude: lat_lon.map(|v| v.1),
+            time_difference_a,
+            time_difference_b,
+            speed_over_ground,
+            true_course: true_course.map(normalize_heading),
+            magnetic_variation: variation.map(|v| if variation_dir == Some('W') { -v } else { v }),
+        },
+    ))
+}
+
+/// Parse RMA (Recommended Minimum Specific Loran-C Data)
+/// from gpsd:
+/// $--RMA,A,llll.ll,a,yyyyy.yy,a,x.x,x.x,x.x,x.x,x.x,a*hh
+/// 1     A          Status: A = valid, V = warning
+/// 2,3   llll.ll,a  Latitude, N/S
+/// 4,5   yyyyy.yy,a Longitude, E/W
+/// 6     x.x        Time difference A, microseconds
+/// 7     x.x        Time difference B, microseconds
+/// 8     x.x        Speed over ground, knots
+/// 9     x.x        Course over ground, degrees true
+/// 10,11 x.x,a      Magnetic variation, E/W
+/// 12    a          FAA mode indicator (NMEA 2.3 and later)
+#[cfg(feature = "rma")]
+fn parse_rma(s: &NmeaSentence) -> Result<RmaData, NmeaError> {
+    if s.message_id != b"RMA" {
+        return Err(NmeaError::unexpected_sentence(
+            SentenceType::RMA,
+            s.message_id,
+        ));
+    }
+    let ret: RmaData = do_parse_rma(s.data)
+        .map(|(_, data)| data)
+        .map_err(NmeaError::from)?;
+    check_lat_lon(ret.latitude, ret.longitude)?;
+    Ok(ret)
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TemperatureUnit {
+    Celsius,
+    Fahrenheit,
+}
+
+/// Temperature in Celsius, standard requires `C` unit,
+/// so missing unit is treated as Celsius
+fn to_celsius(temperature: Option<f32>, unit: Option<TemperatureUnit>) -> Option<f32> {
+    match unit {
+        Some(TemperatureUnit::Fahrenheit) => temperature.map(|t| (t - 32.) * 5. / 9.),
+        Some(TemperatureUnit::Celsius) | None => temperature,
+    }
+}
+
+#[cfg(any(feature = "mtw", feature = "mta"))]
+fn do_parse_temperature(i: &[u8]) -> IResult<&[u8], (Option<f32>, Option<TemperatureUnit>)> {
+    let (i, temperature) = opt(float)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, unit) = opt(alt((
+        value(TemperatureUnit::Celsius, char('C')),
+        value(TemperatureUnit::Fahrenheit, char('F')),
+    )))(i)?;
+    Ok((i, (temperature, unit)))
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MtwData {
+    /// Water temperature in `unit`
+    pub temperature: Option<f32>,
+    pub unit: Option<TemperatureUnit>,
+}
+
+impl MtwData {
+    /// Water temperature in Celsius, converted if reported in Fahrenheit
+    pub fn temperature_celsius(&self) -> Option<f32> {
+        to_celsius(self.temperature, self.unit)
+    }
+}
+
+/// Parse MTW (Mean Temperature of Water)
+/// $--MTW,x.x,C*hh
+/// 1,2 x.x,C  Temperature, degrees Celsius
+#[cfg(feature = "mtw")]
+fn parse_mtw(s: &NmeaSentence) -> Result<MtwData, NmeaError> {
+    if s.message_id != b"MTW" {
+        return Err(NmeaError::unexpected_sentence(
+            SentenceType::MTW,
+            s.message_id,
+        ));
+    }
+    let (temperature, unit) = do_parse_temperature(s.data)
+        .map(|(_, data)| data)
+        .map_err(NmeaError::from)?;
+    Ok(MtwData { temperature, unit })
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MtaData {
+    /// Air temperature in `unit`
+    pub temperature: Option<f32>,
+    pub unit: Option<TemperatureUnit>,
+}
+
+impl MtaData {
+    /// Air temperature in Celsius, converted if reported in Fahrenheit
+    pub fn temperature_celsius(&self) -> Option<f32> {
+        to_celsius(self.temperature, self.unit)
+    }
+}
+
+/// Parse MTA (Air Temperature)
+/// $--MTA,x.x,C*hh
+/// 1,2 x.x,C  Temperature, degrees Celsius
+#[cfg(feature = "mta")]
+fn parse_mta(s: &NmeaSentence) -> Result<MtaData, NmeaError> {
+    if s.message_id != b"MTA" {
+        return Err(NmeaError::unexpected_sentence(
+            SentenceType::MTA,
+            s.message_id,
+        ));
+    }
+    let (temperature, unit) = do_parse_temperature(s.data)
+        .map(|(_, data)| data)
+        .map_err(NmeaError::from)?;
+    Ok(MtaData { temperature, unit })
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TllData {
+    pub target_number: u16,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub name: String,
+    pub time: Option<NaiveTime>,
+    /// `L` lost, `Q` query (acquiring), `T` tracking
+    pub status: char,
+    /// `R` if target is reference one, used to determine own ship position
+    pub reference_target: Option<char>,
+}
+
+#[cfg(feature = "tll")]
+fn do_parse_tll(i: &[u8]) -> IResult<&[u8], TllData> {
+    let (i, target_number) = number::<u16>(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, lat_lon) = parse_lat_lon(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, name) = map_res(take_until(","), str::from_utf8)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, time) = opt(parse_hms)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, status) = one_of("LQT")(i)?;
+    let (i, reference_target) = opt(preceded(char(','), opt(char('R'))))(i)?;
+
+    Ok((
+        i,
+        TllData {
+            target_number,
+            latitude: lat_lon.map(|v| v.0),
+            longitude: lat_lon.map(|v| v.1),
+            name: name.to_string(),
+            time,
+            status,
+            reference_target: reference_target.flatten(),
+        },
+    ))
+}
+
+/// Parse TLL (Target Latitude and Longitude)
+/// $--TLL,xx,llll.ll,a,yyyyy.yy,a,c--c,hhmmss.ss,a,a*hh
+/// 1     xx           Target number 00 - 99
+/// 2,3   llll.ll,a    Latitude, N/S
+/// 4,5   yyyyy.yy,a   Longitude, E/W
+/// 6     c--c         Target name
+/// 7     hhmmss.ss    UTC of data
+/// 8     a            Target status: L = lost, Q = query, T = tracking
+/// 9     a            Reference target: R or empty
+#[cfg(feature = "tll")]
+fn parse_tll(s: &NmeaSentence) -> Result<TllData, NmeaError> {
+    if s.message_id != b"TLL" {
+        return Err(NmeaError::unexpected_sentence(
+            SentenceType::TLL,
+            s.message_id,
+        ));
+    }
+    let ret: TllData = do_parse_tll(s.data)
+        .map(|(_, data)| data)
+        .map_err(NmeaError::from)?;
+    check_lat_lon(ret.latitude, ret.longitude)?;
+    Ok(ret)
+}
+
+#[derive(Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GstData {
+    pub fix_time: Option<NaiveTime>,
+    /// RMS value of standard deviation of range inputs to navigation process
+    pub rms_sd: Option<f32>,
+    /// Standard deviation of semi-major axis of error ellipse in meters
+    pub ellipse_semi_major_sd: Option<f32>,
+    /// Standard deviation of semi-minor axis of error ellipse in meters
+    pub ellipse_semi_minor_sd: Option<f32>,
+    /// Orientation of semi-major axis of error ellipse in degrees from true north
+    pub ellipse_orientation: Option<f32>,
+    /// Standard deviation of latitude error in meters
+    pub lat_sd: Option<f32>,
+    /// Standard deviation of longitude error in meters
+    pub lon_sd: Option<f32>,
+    /// Standard deviation of altitude error in meters
+    pub alt_sd: Option<f32>,
+}
+
+impl GstData {
+    /// Latitude accuracy in meters for `sigma` multiplier of standard deviation,
+    /// like `1.` for 68% or `2.` for 95% confidence
+    pub fn lat_accuracy(&self, sigma: f32) -> Option<f32> {
+        self.lat_sd.map(|sd| sd * sigma)
+    }
+
+    /// Longitude accuracy in meters, see `lat_accuracy`
+    pub fn lon_accuracy(&self, sigma: f32) -> Option<f32> {
+        self.lon_sd.map(|sd| sd * sigma)
+    }
+
+    /// Altitude accuracy in meters, see `lat_accuracy`
+    pub fn alt_accuracy(&self, sigma: f32) -> Option<f32> {
+        self.alt_sd.map(|sd| sd * sigma)
+    }
+}
+
+#[cfg(feature = "gst")]
+fn do_parse_gst(i: &[u8]) -> IResult<&[u8], GstData> {
+    let (i, fix_time) = opt(parse_hms)(i)?;
+    let (i, rms_sd) = preceded(char(','), opt(float))(i)?;
+    let (i, ellipse_semi_major_sd) = preceded(char(','), opt(float))(i)?;
+    let (i, ellipse_semi_minor_sd) = preceded(char(','), opt(float))(i)?;
+    let (i, ellipse_orientation) = preceded(char(','), opt(float))(i)?;
+    let (i, lat_sd) = preceded(char(','), opt(float))(i)?;
+    let (i, lon_sd) = preceded(char(','), opt(float))(i)?;
+    let (i, alt_sd) = preceded(char(','), opt(float))(i)?;
+
+    Ok((
+        i,
+        GstData {
+            fix_time,
+            rms_sd,
+            ellipse_semi_major_sd,
+            ellipse_semi_minor_sd,
+            ellipse_orientation,
+            lat_sd,
+            lon_sd,
+            alt_sd,
+        },
+    ))
+}
+
+/// Parse GST (GNSS Pseudorange Error Statistics)
+/// $--GST,hhmmss.ss,x.x,x.x,x.x,x.x,x.x,x.x,x.x*hh
+/// 1     hhmmss.ss    UTC time of associated GGA fix
+/// 2     x.x          RMS value of standard deviation of range inputs
+/// 3     x.x          Standard deviation of semi-major axis of error ellipse, meters
+/// 4     x.x          Standard deviation of semi-minor axis of error ellipse, meters
+/// 5     x.x          Orientation of semi-major axis, degrees from true north
+/// 6     x.x          Standard deviation of latitude error, meters
+/// 7     x.x          Standard deviation of longitude error, meters
+/// 8     x.x          Standard deviation of altitude error, meters
+#[cfg(feature = "gst")]
+fn parse_gst(s: &NmeaSentence) -> Result<GstData, NmeaError> {
+    if s.message_id != b"GST" {
+        return Err(NmeaError::unexpected_sentence(
+            SentenceType::GST,
+            s.message_id,
+        ));
+    }
+    let ret: GstData = do_parse_gst(s.data)
+        .map(|(_, data)| data)
+        .map_err(NmeaError::from)?;
+    Ok(ret)
+}
+
+/// Position quality of Trimble GGK sentence
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GgkQuality {
+    /// Fix not available or invalid
+    Invalid,
+    /// Autonomous GPS fix
+    Autonomous,
+    /// Real Time Kinematic with float integers
+    RtkFloat,
+    /// Real Time Kinematic with fixed integers
+    RtkFixed,
+    /// Differential, code phase only
+    DGps,
+    /// SBAS (WAAS, EGNOS, MSAS) corrected
+    Sbas,
+    /// Network RTK with float integers
+    NetworkRtkFloat,
+    /// Value not covered above, receivers report some vendor specific ones
+    Other(u8),
+}
+
+impl From<u8> for GgkQuality {
+    fn from(x: u8) -> Self {
+        match x {
+            0 => GgkQuality::Invalid,
+            1 => GgkQuality::Autonomous,
+            2 => GgkQuality::RtkFloat,
+            3 => GgkQuality::RtkFixed,
+            4 => GgkQuality::DGps,
+            5 => GgkQuality::Sbas,
+            6 => GgkQuality::NetworkRtkFloat,
+            x => GgkQuality::Other(x),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GgkData {
+    pub fix_time: Option<NaiveTime>,
+    pub fix_date: Option<NaiveDate>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub quality: Option<GgkQuality>,
+    pub fix_satellites: Option<u32>,
+    /// Dilution of precision
+    pub dop: Option<f32>,
+    /// Height above ellipsoid in meters
+    pub ellipsoidal_height: Option<f32>,
+}
+
+/// GGK date is `mmddyy`, unlike `ddmmyy` of RMC
+#[cfg(feature = "ggk")]
+fn parse_mdy_date(i: &[u8]) -> IResult<&[u8], NaiveDate> {
+    map_res(
+        tuple((
+            map_res(take(2usize), parse_num::<u8>),
+            map_res(take(2usize), parse_num::<u8>),
+            map_res(take(2usize), parse_num::<u8>),
+        )),
+        |(month, day, year)| {
+            NaiveDate::from_ymd_opt(full_year(year), u32::from(month), u32::from(day))
+                .ok_or("Invalid date")
+        },
+    )(i)
+}
+
+#[cfg(feature = "ggk")]
+fn do_parse_ggk(i: &[u8]) -> IResult<&[u8], GgkData> {
+    let (i, fix_time) = opt(parse_hms)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, fix_date) = opt(parse_mdy_date)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, lat_lon) = parse_lat_lon(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, quality) = opt(number::<u8>)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, fix_satellites) = opt(number::<u32>)(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, dop) = dop(i)?;
+    let (i, _) = char(',')(i)?;
+    let (i, ellipsoidal_height) = opt(preceded(tag("EHT"), float))(i)?;
+    let (i, _) = opt(preceded(char(','), opt(char('M'))))(i)?;
+
+    Ok((
+        i,
+        GgkData {
+            fix_time,
+            fix_date,
+            latitude: lat_lon.map(|v| v.0),
+            longitude: lat_lon.map(|v| v.1),
+            quality: quality.map(GgkQuality::from),
+            fix_satellites,
+            dop,
+            ellipsoidal_height,
+        },
+    ))
+}
+
+/// Parse GGK (Trimble time, position, position type and DOP)
+/// $--GGK,hhmmss.ss,mmddyy,llll.ll,a,yyyyy.yy,a,x,xx,x.x,EHTx.x,M*hh
+/// 1     hhmmss.ss    UTC time of position fix
+/// 2     mmddyy       UTC date
+/// 3,4   llll.ll,a    Latitude, N/S
+/// 5,6   yyyyy.yy,a   Longitude, E/W
+/// 7     x            Position quality, see `GgkQuality`
+/// 8     xx           Number of satellites in fix
+/// 9     x.x          Dilution of precision
+/// 10    EHTx.x       Height above ellipsoid
+/// 11    M            Units of height, meters
+#[cfg(feature = "ggk")]
+fn parse_ggk(s: &NmeaSentence) -> Result<GgkData, NmeaError> {
+    if s.message_id != b"GGK" {
+        return Err(NmeaError::unexpected_sentence(
+            SentenceType::GGK,
+            s.message_id,
+        ));
+    }
+    let ret: GgkData = do_parse_ggk(s.data)
+        .map(|(_, data)| data)
+        .map_err(NmeaError::from)?;
+    check_lat_lon(ret.latitude, ret.longitude)?;
+    Ok(ret)
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
+pub enum ParseResult {
+    #[cfg(feature = "gga")]
+    GGA(GgaData),
+    #[cfg(feature = "rmc")]
+    RMC(RmcData),
+    #[cfg(feature = "gsv")]
+    GSV(GsvData),
+    #[cfg(feature = "gsa")]
+    GSA(GsaData),
+    #[cfg(feature = "vtg")]
+    VTG(VtgData),
+    #[cfg(feature = "gll")]
+    GLL(GllData),
+    #[cfg(feature = "vdr")]
+    VDR(VdrData),
+    #[cfg(feature = "hdm")]
+    HDM(HdmData),
+    #[cfg(feature = "ztg")]
+    ZTG(ZtgData),
+    #[cfg(feature = "gns")]
+    GNS(GnsData),
+    #[cfg(feature = "rma")]
+    RMA(RmaData),
+    #[cfg(feature = "mtw")]
+    MTW(MtwData),
+    #[cfg(feature = "mta")]
+    MTA(MtaData),
+    #[cfg(feature = "tll")]
+    TLL(TllData),
+    #[cfg(feature = "gst")]
+    GST(GstData),
+    #[cfg(feature = "ggk")]
+    GGK(GgkData),
+    /// Sentence is not supported or its parser is disabled by features,
+    /// `talker` is `TalkerId::Other` if talker id is unknown too
+    Unsupported {
+        talker: TalkerId,
+        sentence: SentenceType,
+    },
+}
+
+impl ParseResult {
+    /// Type of parsed sentence, for `Unsupported` the type it reports
+    pub fn sentence_type(&self) -> SentenceType {
+        match self {
+            #[cfg(feature = "gga")]
+            ParseResult::GGA(_) => SentenceType::GGA,
+            #[cfg(feature = "rmc")]
+            ParseResult::RMC(_) => SentenceType::RMC,
+            #[cfg(feature = "gsv")]
+            ParseResult::GSV(_) => SentenceType::GSV,
+            #[cfg(feature = "gsa")]
+            ParseResult::GSA(_) => SentenceType::GSA,
+            #[cfg(feature = "vtg")]
+            ParseResult::VTG(_) => SentenceType::VTG,
+            #[cfg(feature = "gll")]
+            ParseResult::GLL(_) => SentenceType::GLL,
+            #[cfg(feature = "vdr")]
+            ParseResult::VDR(_) => SentenceType::VDR,
+            #[cfg(feature = "hdm")]
+            ParseResult::HDM(_) => SentenceType::HDM,
+            #[cfg(feature = "ztg")]
+            ParseResult::ZTG(_) => SentenceType::ZTG,
+            #[cfg(feature = "gns")]
+            ParseResult::GNS(_) => SentenceType::GNS,
+            #[cfg(feature = "rma")]
+            ParseResult::RMA(_) => SentenceType::RMA,
+            #[cfg(feature = "mtw")]
+            ParseResult::MTW(_) => SentenceType::MTW,
+            #[cfg(feature = "mta")]
+            ParseResult::MTA(_) => SentenceType::MTA,
+            #[cfg(feature = "tll")]
+            ParseResult::TLL(_) => SentenceType::TLL,
+            #[cfg(feature = "gst")]
+            ParseResult::GST(_) => SentenceType::GST,
+            #[cfg(feature = "ggk")]
+            ParseResult::GGK(_) => SentenceType::GGK,
+            ParseResult::Unsupported { sentence, .. } => sentence.clone(),
+        }
+    }
+
+    /// Is it sentence reporting position: GGA, RMC, GLL or GNS
+    pub fn is_position(&self) -> bool {
+        match self {
+            #[cfg(feature = "gga")]
+            ParseResult::GGA(_) => true,
+            #[cfg(feature = "rmc")]
+            ParseResult::RMC(_) => true,
+            #[cfg(feature = "gll")]
+            ParseResult::GLL(_) => true,
+            #[cfg(feature = "gns")]
+            ParseResult::GNS(_) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Options to control how strict `parse_with_options` is
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParseOptions {
+    /// Accept GGA and RMC sentences which end before all fields,
+    /// fields missing at the end are reported as `None`
+    pub lenient: bool,
+    /// Return `NmeaError::NoFix` for GGA, RMC, GNS and GSA sentences
+    /// which report that receiver has no position fix
+    pub skip_invalid_fix: bool,
+    /// Accept comma as decimal separator in numeric fields, like `48,5`,
+    /// written by some loggers with European locale. Applied only to
+    /// sentences with more fields than required, and only if there is
+    /// exactly one way to match fields to field types with joined numbers.
+    pub decimal_comma: bool,
+    /// Max number of PRNs kept from GSA sentence, the rest are skipped
+    pub max_gsa_prns: usize,
+}
+
+/// Strict parsing, with `MAX_GSA_PRNS` limit of GSA PRNs
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions {
+            lenient: false,
+            skip_invalid_fix: false,
+            decimal_comma: false,
+            max_gsa_prns: MAX_GSA_PRNS,
+        }
+    }
+}
+
+/// Kinds of fields of sentence in order: `f` float number,
+/// `u` unsigned integer, `a` anything but number (including empty),
+/// and number of fields required by the oldest NMEA version, trailing
+/// fields after it were added later (like FAA mode of NMEA 2.3)
+fn field_kinds(msg_type: SentenceType) -> Option<(&'static [u8], usize)> {
+    match msg_type {
+        SentenceType::GGA => Some((b"ffafauuffafafu", 14)),
+        SentenceType::RMC => Some((b"fafafaffufaaa", 11)),
+        SentenceType::VTG => Some((b"fafafafaa", 8)),
+        SentenceType::GLL => Some((b"fafafaa", 6)),
+        SentenceType::VDR => Some((b"fafafa", 6)),
+        SentenceType::HDM | SentenceType::MTW | SentenceType::MTA => Some((b"fa", 2)),
+        SentenceType::GNS => Some((b"ffafaauffffua", 12)),
+        SentenceType::RMA => Some((b"afafafffffaa", 11)),
+        _ => None,
+    }
+}
+
+fn is_digits(field: &[u8]) -> bool {
+    !field.is_empty() && field.iter().all(u8::is_ascii_digit)
+}
+
+fn field_matches(kind: u8, field: &[u8]) -> bool {
+    match kind {
+        b'f' => {
+            let field = field.strip_prefix(b"-").unwrap_or(field);
+            field.is_empty() || parse_float_num::<f64>(field).is_ok()
+        }
+        b'u' => field.is_empty() || is_digits(field),
+        _ => field.is_empty() || !is_digits(field),
+    }
+}
+
+/// Match `fields` to `kinds`, last `optional` kinds may be missing.
+/// Float field can take two fields split by decimal comma, indexes
+/// of such fields are collected into `joins`. Returns number of possible
+/// matches, but stops search after second one.
+fn match_decimal_comma(
+    kinds: &[u8],
+    optional: usize,
+    fields: &[&[u8]],
+    joins: &mut Vec<usize>,
+    pos: usize,
+) -> usize {
+    let (kind, field) = match (kinds.first(), fields.get(pos)) {
+        (None, None) => return 1,
+        (Some(_), None) => return usize::from(kinds.len() <= optional),
+        (Some(kind), Some(field)) => (*kind, *field),
+        (None, Some(_)) => return 0,
+    };
+    let mut found = 0;
+    if field_matches(kind, field) {
+        found += match_decimal_comma(&kinds[1..], optional, fields, joins, pos + 1);
+    }
+    let integer = field.strip_prefix(b"-").unwrap_or(field);
+    if found < 2
+        && kind == b'f'
+        && is_digits(integer)
+        && fields.get(pos + 1).is_some_and(|f| is_digits(f))
+    {
+        let len = joins.len();
+        joins.push(pos);
+        let joined = match_decimal_comma(&kinds[1..], optional, fields, joins, pos + 2);
+        if found > 0 || joined != 1 {
+            joins.truncate(len);
+        }
+        found += joined;
+    }
+    found
+}
+
+/// Replace decimal commas with dots in data of sentence,
+/// returns `None` if there is nothing to replace or match is ambiguous
+fn normalize_decimal_comma(s: &NmeaSentence, msg_type: SentenceType) -> Option<OwnedNmeaSentence> {
+    let (kinds, required) = field_kinds(msg_type)?;
+    let fields: Vec<&[u8]> = s.fields().collect();
+    // decimal comma adds field, so there are more fields than required
+    if fields.len() <= required {
+        return None;
+    }
+    let mut joins = Vec::new();
+    let optional = kinds.len() - required;
+    if match_decimal_comma(kinds, optional, &fields, &mut joins, 0) != 1 || joins.is_empty() {
+        return None;
+    }
+    let mut owned = s.to_owned();
+    owned.data.clear();
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            let sep = if joins.contains(&(i - 1)) { b'.' } else { b',' };
+            owned.data.push(sep);
+        }
+        owned.data.extend_from_slice(field);
+    }
+    Some(owned)
+}
+
+/// Pad data of truncated sentence with empty fields,
+/// returns `None` if sentence already has all fields of `msg_type`
+#[cfg(any(feature = "gga", feature = "rmc"))]
+fn pad_truncated(s: &NmeaSentence, msg_type: SentenceType) -> Option<OwnedNmeaSentence> {
+    let nfields = field_names(msg_type).len();
+    let have = s.data.split(|c| *c == b',').count();
+    if have >= nfields {
+        return None;
+    }
+    let mut owned = s.to_owned();
+    owned.data.resize(s.data.len() + nfields - have, b',');
+    Some(owned)
+}
+
+/// parse nmea 0183 sentence and extract data from it
+///
+/// Function never panics, any malformed input, including arbitrary
+/// bytes, is reported as `Err`.
+pub fn parse(xs: &[u8]) -> Result<ParseResult, NmeaError> {
+    parse_with_options(xs, &ParseOptions::default())
+}
+
+/// Same as `parse`, for sentences already in `str`, like lines read from serial port
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "hdm")] {
+/// use nmea::{parse_str, ParseResult};
+///
+/// let line = String::from("$HCHDM,238.5,M*25");
+/// assert!(matches!(parse_str(&line), Ok(ParseResult::HDM(_))));
+/// # }
+/// ```
+pub fn parse_str(s: &str) -> Result<ParseResult, NmeaError> {
+    parse(s.as_bytes())
+}
+
+/// Same as `parse_nmea_sentence`, for sentences in `str`
+pub fn parse_nmea_sentence_str(s: &str) -> Result<NmeaSentence<'_>, NmeaError> {
+    parse_nmea_sentence(s.as_bytes())
+}
+
+/// Envelope of sentence from `str` with verified checksum
+#[cfg(any(
+    feature = "gga",
+    feature = "rmc",
+    feature = "vtg",
+    feature = "gll",
+    feature = "gsa",
+    feature = "gsv"
+))]
+fn parse_checked_sentence(s: &str) -> Result<NmeaSentence<'_>, NmeaError> {
+    let sentence = parse_nmea_sentence_str(s)?;
+    if sentence.checksum != sentence.calc_checksum() {
+        return Err(NmeaError::ChecksumMismatch {
+            expected: sentence.calc_checksum(),
+            found: sentence.checksum,
+        });
+    }
+    Ok(sentence)
+}
+
+/// `FromStr` for data of sentence, so one sentence type can be parsed
+/// with `let gga: GgaData = line.parse()?`, and `TryFrom<&NmeaSentence>`
+/// to get it from already parsed envelope with `GgaData::try_from(&sentence)?`,
+/// other sentence types are reported as `NmeaError::UnexpectedSentence`
+#[cfg(any(
+    feature = "gga",
+    feature = "rmc",
+    feature = "vtg",
+    feature = "gll",
+    feature = "gsa",
+    feature = "gsv"
+))]
+macro_rules! impl_from_str {
+    ($data:ty, $parse:ident) => {
+        impl str::FromStr for $data {
+            type Err = NmeaError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $parse(&parse_checked_sentence(s)?)
+            }
+        }
+
+        impl<'a, 'b> TryFrom<&'a NmeaSentence<'b>> for $data {
+            type Error = NmeaError;
+
+            fn try_from(sentence: &'a NmeaSentence<'b>) -> Result<Self, Self::Error> {
+                $parse(sentence)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "gga")]
+impl_from_str!(GgaData, parse_gga);
+#[cfg(feature = "rmc")]
+impl_from_str!(RmcData, parse_rmc);
+#[cfg(feature = "vtg")]
+impl_from_str!(VtgData, parse_vtg);
+#[cfg(feature = "gll")]
+impl_from_str!(GllData, parse_gll);
+#[cfg(feature = "gsa")]
+impl_from_str!(GsaData, parse_gsa);
+#[cfg(feature = "gsv")]
+impl_from_str!(GsvData, parse_gsv);
+
+/// Same as `parse`, but with control over lenient handling of malformed sentences
+pub fn parse_with_options(xs: &[u8], options: &ParseOptions) -> Result<ParseResult, NmeaError> {
+    let xs = skip_tag_block(xs)?;
+    if xs.starts_with(b"$P") {
+        return parse_proprietary(xs);
+    }
+    parse_sentence(&parse_nmea_sentence(xs)?, options)
+}
+
+/// Proprietary sentences are not decoded, only their frame and checksum checked
+fn parse_proprietary(xs: &[u8]) -> Result<ParseResult, NmeaError> {
+    let sentence = parse_proprietary_sentence(xs)?;
+    if sentence.checksum == sentence.calc_checksum() {
+        Ok(ParseResult::Unsupported {
+            talker: TalkerId::Proprietary,
+            sentence: SentenceType::None,
+        })
+    } else {
+        Err(NmeaError::ChecksumMismatch {
+            expected: sentence.calc_checksum(),
+            found: sentence.checksum,
+        })
+    }
+}
+
+/// Same as `parse`, but also returns talker of sentence,
+/// for example to tell GPGGA from GNGGA
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "gsa")] {
+/// use nmea::{parse_full, ParseResult, TalkerId};
+///
+/// let (talker, data) =
+///     parse_full(b"$GNGSA,A,3,31,26,21,,,,,,,,,,3.77,2.55,2.77*1A").unwrap();
+/// assert_eq!(talker, TalkerId::GnssCombined);
+/// assert!(matches!(data, ParseResult::GSA(_)));
+/// # }
+/// ```
+pub fn parse_full(xs: &[u8]) -> Result<(TalkerId, ParseResult), NmeaError> {
+    let xs = skip_tag_block(xs)?;
+    if xs.starts_with(b"$P") {
+        return Ok((TalkerId::Proprietary, parse_proprietary(xs)?));
+    }
+    let nmea_sentence = parse_nmea_sentence(xs)?;
+    let data = parse_sentence(&nmea_sentence, &ParseOptions::default())?;
+    Ok((nmea_sentence.talker(), data))
+}
+
+/// Parse buffer with several sentences, separated by `\r\n` or `\n`.
+/// Empty lines are skipped, malformed line gives `Err` item
+/// without stopping iteration.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::parse_lines;
+///
+/// let buf = b"$HCHDM,238.5,M*25\r\ngarbage\r\n\r\n$HCHDM,238.5,M*25\r\n";
+/// let results: Vec<_> = parse_lines(buf).collect();
+/// assert_eq!(results.len(), 3);
+/// assert!(results[1].is_err());
+/// ```
+pub fn parse_lines(bytes: &[u8]) -> impl Iterator<Item = Result<ParseResult, NmeaError>> + '_ {
+    split_lines(bytes).map(parse)
+}
+
+/// Split buffer into sentences like `parse_lines`, but only check their
+/// frame and checksum without decoding of data. Sentences can then
+/// be routed by `message_id` and parsed with `parse_gga`, `parse_rmc`, etc.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::split_sentences;
+///
+/// let buf = b"$HCHDM,238.5,M*25\r\n$HCHDM,238.5,M*26\r\n";
+/// let sentences: Vec<_> = split_sentences(buf).collect();
+/// assert_eq!(sentences[0].as_ref().unwrap().message_id, b"HDM");
+/// assert!(sentences[1].is_err());
+/// ```
+pub fn split_sentences(
+    bytes: &[u8],
+) -> impl Iterator<Item = Result<NmeaSentence<'_>, NmeaError>> + '_ {
+    split_lines(bytes).map(|line| {
+        let sentence = parse_nmea_sentence(line)?;
+        if sentence.checksum == sentence.calc_checksum() {
+            Ok(sentence)
+        } else {
+            Err(NmeaError::ChecksumMismatch {
+                expected: sentence.calc_checksum(),
+                found: sentence.checksum,
+            })
+        }
+    })
+}
+
+fn split_lines(bytes: &[u8]) -> impl Iterator<Item = &[u8]> {
+    bytes
+        .split(|c| *c == b'\n')
+        .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+        .filter(|line| !line.is_empty())
+}
+
+/// Sentence carries fix status and it says there is no fix
+fn has_no_fix(result: &ParseResult) -> bool {
+    match result {
+        #[cfg(feature = "gga")]
+        ParseResult::GGA(gga) => gga.fix_type == Some(FixType::Invalid),
+        #[cfg(feature = "rmc")]
+        ParseResult::RMC(rmc) => rmc.status_of_fix == Some(RmcStatusOfFix::Invalid),
+        #[cfg(feature = "gns")]
+        ParseResult::GNS(gns) => {
+            !gns.mode_indicator.is_empty() && gns.mode_indicator.chars().all(|c| c == 'N')
+        }
+        #[cfg(feature = "gsa")]
+        ParseResult::GSA(gsa) => gsa.mode2 == GsaMode2::NoFix,
+        _ => false,
+    }
+}
+
+/// `!` sentences (AIS VDM/VDO and others) have encapsulated binary data,
+/// decoding of which is not supported
+fn parse_encapsulated(nmea_sentence: &NmeaSentence) -> Result<ParseResult, NmeaError> {
+    Ok(ParseResult::Unsupported {
+        talker: nmea_sentence.talker(),
+        sentence: nmea_sentence.sentence_type()?,
+    })
+}
+
+fn parse_sentence(
+    nmea_sentence: &NmeaSentence,
+    options: &ParseOptions,
+) -> Result<ParseResult, NmeaError> {
+    if nmea_sentence.checksum == nmea_sentence.calc_checksum() {
+        if nmea_sentence.start_delimiter == b'!' {
+            return parse_encapsulated(nmea_sentence);
+        }
+        let msg_type = nmea_sentence.sentence_type()?;
+        let normalized = if options.decimal_comma {
+            normalize_decimal_comma(nmea_sentence, msg_type.clone())
+        } else {
+            None
+        };
+        let normalized = normalized.as_ref().map(OwnedNmeaSentence::as_sentence);
+        let nmea_sentence = normalized.as_ref().unwrap_or(nmea_sentence);
+        #[cfg(any(feature = "gga", feature = "rmc"))]
+        let padded = |msg_type| {
+            if options.lenient {
+                pad_truncated(nmea_sentence, msg_type)
+            } else {
+                None
+            }
+        };
+        let result = match msg_type {
+            #[cfg(feature = "gga")]
+            SentenceType::GGA => {
+                let data = match padded(SentenceType::GGA) {
+                    Some(owned) => parse_gga(&owned.as_sentence())?,
+                    None => parse_gga(nmea_sentence)?,
+                };
+                ParseResult::GGA(data)
+            }
+            #[cfg(feature = "gsv")]
+            SentenceType::GSV => {
+                let data = parse_gsv(nmea_sentence)?;
+                ParseResult::GSV(data)
+            }
+            #[cfg(feature = "rmc")]
+            SentenceType::RMC => {
+                let data = match padded(SentenceType::RMC) {
+                    Some(owned) => parse_rmc(&owned.as_sentence())?,
+                    None => parse_rmc(nmea_sentence)?,
+                };
+                ParseResult::RMC(data)
+            }
+            #[cfg(feature = "gsa")]
+            SentenceType::GSA => {
+                ParseResult::GSA(parse_gsa_capped(nmea_sentence, options.max_gsa_prns)?)
+            }
+            #[cfg(feature = "vtg")]
+            SentenceType::VTG => ParseResult::VTG(parse_vtg(nmea_sentence)?),
+            #[cfg(feature = "gll")]
+            SentenceType::GLL => ParseResult::GLL(parse_gll(nmea_sentence)?),
+            #[cfg(feature = "vdr")]
+            SentenceType::VDR => ParseResult::VDR(parse_vdr(nmea_sentence)?),
+            #[cfg(feature = "hdm")]
+            SentenceType::HDM => ParseResult::HDM(parse_hdm(nmea_sentence)?),
+            #[cfg(feature = "ztg")]
+            SentenceType::ZTG => ParseResult::ZTG(parse_ztg(nmea_sentence)?),
+            #[cfg(feature = "gns")]
+            SentenceType::GNS => ParseResult::GNS(parse_gns(nmea_sentence)?),
+            #[cfg(feature = "rma")]
+            SentenceType::RMA => ParseResult::RMA(parse_rma(nmea_sentence)?),
+            #[cfg(feature = "mtw")]
+            SentenceType::MTW => ParseResult::MTW(parse_mtw(nmea_sentence)?),
+            #[cfg(feature = "mta")]
+            SentenceType::MTA => ParseResult::MTA(parse_mta(nmea_sentence)?),
+            #[cfg(feature = "tll")]
+            SentenceType::TLL => ParseResult::TLL(parse_tll(nmea_sentence)?),
+            #[cfg(feature = "gst")]
+            SentenceType::GST => ParseResult::GST(parse_gst(nmea_sentence)?),
+            #[cfg(feature = "ggk")]
+            SentenceType::GGK => ParseResult::GGK(parse_ggk(nmea_sentence)?),
+            sentence => ParseResult::Unsupported {
+                talker: nmea_sentence.talker(),
+                sentence,
+            },
+        };
+        if options.skip_invalid_fix && has_no_fix(&result) {
+            return Err(NmeaError::NoFix);
+        }
+        Ok(result)
+    } else {
+        Err(NmeaError::ChecksumMismatch {
+            expected: nmea_sentence.calc_checksum(),
+            found: nmea_sentence.checksum,
+        })
+    }
+}
+
+/// Names of comma separated data fields (without talker/message id
+/// and checksum) in order, for sentence types supported by `parse`.
+/// For not supported sentence types returns empty slice.
+///
+/// # Examples
+///
+/// ```
+/// use nmea::{field_names, SentenceType};
+///
+/// assert_eq!(field_names(SentenceType::GGA)[7], "HDOP");
+/// ```
+pub fn field_names(t: SentenceType) -> &'static [&'static str] {
+    match t {
+        SentenceType::GGA => &[
+            "UTC time",
+            "Latitude",
+            "N/S indicator",
+            "Longitude",
+            "E/W indicator",
+            "Fix quality",
+            "Number of satellites",
+            "HDOP",
+            "Altitude",
+            "Altitude units",
+            "Geoid height",
+            "Geoid height units",
+            "Age of DGPS data",
+            "DGPS station ID",
+        ],
+        SentenceType::RMC => &[
+            "UTC time",
+            "Status",
+            "Latitude",
+            "N/S indicator",
+            "Longitude",
+            "E/W indicator",
+            "Speed over ground",
+            "Course over ground",
+            "Date",
+            "Magnetic variation",
+            "Magnetic variation E/W indicator",
+            "Mode",
+            "Navigational status",
+        ],
+        SentenceType::GSV => &[
+            "Number of sentences",
+            "Sentence number",
+            "Satellites in view",
+            "Satellite 1 PRN",
+            "Satellite 1 elevation",
+            "Satellite 1 azimuth",
+            "Satellite 1 SNR",
+            "Satellite 2 PRN",
+            "Satellite 2 elevation",
+            "Satellite 2 azimuth",
+            "Satellite 2 SNR",
+            "Satellite 3 PRN",
+            "Satellite 3 elevation",
+            "Satellite 3 azimuth",
+            "Satellite 3 SNR",
+            "Satellite 4 PRN",
+            "Satellite 4 elevation",
+            "Satellite 4 azimuth",
+            "Satellite 4 SNR",
+        ],
+        SentenceType::GSA => &[
+            "Mode 1",
+            "Mode 2",
+            "PRN 1",
+            "PRN 2",
+            "PRN 3",
+            "PRN 4",
+            "PRN 5",
+            "PRN 6",
+            "PRN 7",
+            "PRN 8",
+            "PRN 9",
+            "PRN 10",
+            "PRN 11",
+            "PRN 12",
+            "PDOP",
+            "HDOP",
+            "VDOP",
+            "System ID",
+        ],
+        SentenceType::VTG => &[
+            "True course",
+            "True course indicator",
+            "Magnetic course",
+            "Magnetic course indicator",
+            "Speed over ground, knots",
+            "Knots indicator",
+            "Speed over ground, km/h",
+            "Km/h indicator",
+            "Mode",
+        ],
+        SentenceType::GLL => &[
+            "Latitude",
+            "N/S indicator",
+            "Longitude",
+            "E/W indicator",
+            "UTC time",
+            "Status",
+            "Mode",
+        ],
+        SentenceType::VDR => &[
+            "Direction, true",
+            "True direction indicator",
+            "Direction, magnetic",
+            "Magnetic direction indicator",
+            "Drift, knots",
+            "Knots indicator",
+        ],
+        SentenceType::HDM => &["Heading, magnetic", "Magnetic heading indicator"],
+        SentenceType::ZTG => &["UTC time", "Time to go", "Waypoint ID"],
+        SentenceType::GNS => &[
+            "UTC time",
+            "Latitude",
+            "N/S indicator",
+            "Longitude",
+            "E/W indicator",
+            "Mode indicator",
+            "Number of satellites",
+            "HDOP",
+            "Altitude",
+            "Geoid height",
+            "Age of differential data",
+            "Differential station ID",
+            "Navigational status",
+        ],
+        SentenceType::RMA => &[
+            "Status",
+            "Latitude",
+            "N/S indicator",
+            "Longitude",
+            "E/W indicator",
+            "Time difference A",
+            "Time difference B",
+            "Speed over ground",
+            "Course over ground",
+            "Magnetic variation",
+            "E/W indicator",
+            "Mode",
+        ],
+        SentenceType::MTW => &["Water temperature", "Unit"],
+        SentenceType::MTA => &["Air temperature", "Unit"],
+        SentenceType::TLL => &[
+            "Target number",
+            "Latitude",
+            "N/S indicator",
+            "Longitude",
+            "E/W indicator",
+            "Target name",
+            "UTC time",
+            "Target status",
+            "Reference target",
+        ],
+        SentenceType::GST => &[
+            "UTC time",
+            "RMS of range standard deviation",
+            "Error ellipse semi-major axis standard deviation",
+            "Error ellipse semi-minor axis standard deviation",
+            "Error ellipse orientation",
+            "Latitude error standard deviation",
+            "Longitude error standard deviation",
+            "Altitude error standard deviation",
+        ],
+        SentenceType::GGK => &[
+            "UTC time",
+            "UTC date",
+            "Latitude",
+            "N/S",
+            "Longitude",
+            "E/W",
+            "Position quality",
+            "Number of satellites",
+            "DOP",
+            "Ellipsoidal height",
+            "Units of height",
+        ],
+        _ => &[],
+    }
+}
+
+#[cfg(all(test, feature = "all-sentences"))]
+mod tests {
+    use super::*;
+    use approx::relative_eq;
+
+    #[test]
+    fn test_do_parse_lat_lon() {
         let (_, lat_lon) = do_parse_lat_lon(b"4807.038,N,01131.324,E").unwrap();
         relative_eq!(lat_lon.0, 48. + 7.038 / 60.);
         relative_eq!(lat_lon.1, 11. + 31.324 / 60.);
     }
 
     #[test]
-    fn test_parse_gga_full() {
-        let data = parse_gga(&NmeaSentence {
+    fn test_parse_lat_lon_edge_values() {
+        let (_, lat_lon) = do_parse_lat_lon(b"0000.000,N,18000.000,E").unwrap();
+        assert_eq!(lat_lon, (0., 180.));
+        let (_, lat_lon) = do_parse_lat_lon(b"0000.000,S,18000.000,W").unwrap();
+        assert_eq!(lat_lon, (0., -180.));
+        assert!(lat_lon.0.is_sign_positive());
+        let (_, lat_lon) = do_parse_lat_lon(b"9000.000,S,00000.000,W").unwrap();
+        assert_eq!(lat_lon, (-90., 0.));
+        assert!(lat_lon.1.is_sign_positive());
+        let (_, lat_lon) = do_parse_lat_lon(b"9000.000,N,17959.999,W").unwrap();
+        assert_eq!(lat_lon, (90., -(179. + 59.999 / 60.)));
+    }
+
+    #[test]
+    fn test_parse_lat_lon_without_hemisphere() {
+        let (_, lat_lon) = do_parse_lat_lon(b"4807.038,,01131.324,W").unwrap();
+        assert_eq!(lat_lon.0, 48. + 7.038 / 60.);
+        assert_eq!(lat_lon.1, -(11. + 31.324 / 60.));
+
+        let s = parse_nmea_sentence(
+            b"$GPGGA,092750.000,5321.6802,,00630.3372,W,0,8,1.03,61.7,M,55.2,M,,*39",
+        )
+        .unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let data = parse_gga(&s).unwrap();
+        assert_eq!(data.fix_type, Some(FixType::Invalid));
+        assert_eq!(data.latitude.unwrap(), 53. + 21.6802 / 60.);
+        assert_eq!(data.longitude.unwrap(), -(6. + 30.3372 / 60.));
+
+        let s = parse_nmea_sentence(b"$GPGLL,4916.45,N,12311.12,,225444,A,*4A").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let data = parse_gll(&s).unwrap();
+        assert_eq!(data.latitude, 49. + 16.45 / 60.);
+        assert_eq!(data.longitude, 123. + 11.12 / 60.);
+    }
+
+    #[test]
+    fn test_parse_lat_lon_out_of_range() {
+        for (line, err) in &[
+            (
+                "$GPGGA,092750.000,9500.000,S,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*5E",
+                NmeaError::InvalidLatitude,
+            ),
+            (
+                "$GPRMC,092750.000,A,8975.000,N,00630.3372,W,0.02,31.66,280511,,,A*79",
+                NmeaError::InvalidLatitude,
+            ),
+            (
+                "$GPGGA,092750.000,5321.6802,N,18100.000,W,1,8,1.03,61.7,M,55.2,M,,*4E",
+                NmeaError::InvalidLongitude,
+            ),
+            (
+                "$GPGLL,5107.0013414,N,19902.3279144,E,205412.00,A,A*64",
+                NmeaError::InvalidLongitude,
+            ),
+        ] {
+            assert_eq!(parse_str(line).err().as_ref(), Some(err), "{}", line);
+        }
+
+        match parse(b"$GPGLL,9000.000,S,18000.000,W,205412.00,A,A*66") {
+            Ok(ParseResult::GLL(gll)) => {
+                assert_eq!(gll.latitude, -90.);
+                assert_eq!(gll.longitude, -180.);
+            }
+            _ => panic!("GLL at edge of range expected"),
+        }
+    }
+
+    #[test]
+    fn test_parse_gga_full() {
+        let data = parse_gga(&NmeaSentence {
+            start_delimiter: b'$',
+            talker_id: b"GP",
+            message_id: b"GGA",
+            data: b"033745.0,5650.82344,N,03548.9778,E,1,07,1.8,101.2,M,14.7,M,,",
+            checksum: 0x57,
+        })
+        .unwrap();
+        assert_eq!(
+            data.fix_time.unwrap(),
+            NaiveTime::from_hms_opt(3, 37, 45).unwrap()
+        );
+        assert_eq!(data.fix_type.unwrap(), FixType::Gps);
+        relative_eq!(data.latitude.unwrap(), 56. + 50.82344 / 60.);
+        relative_eq!(data.longitude.unwrap(), 35. + 48.9778 / 60.);
+        assert_eq!(data.fix_satellites.unwrap(), 7);
+        relative_eq!(data.hdop.unwrap(), 1.8);
+        relative_eq!(data.altitude.unwrap(), 101.2);
+        relative_eq!(data.geoid_height.unwrap(), 14.7);
+
+        let s = parse_nmea_sentence(b"$GPGGA,,,,,,0,,,,,,,,*66").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let data = parse_gga(&s).unwrap();
+        assert_eq!(
+            GgaData {
+                fix_time: None,
+                fix_type: Some(FixType::Invalid),
+                latitude: None,
+                longitude: None,
+                fix_satellites: None,
+                hdop: None,
+                altitude: None,
+                geoid_height: None,
+                age_of_dgps: None,
+                dgps_station_id: None,
+            },
+            data
+        );
+    }
+
+    #[test]
+    fn test_default_data() {
+        let gga = GgaData::default();
+        assert_eq!(gga.fix_time, None);
+        assert_eq!(gga.latitude, None);
+        assert_eq!(RmcData::default().status_of_fix, None);
+        assert_eq!(VtgData::default().speed_over_ground, None);
+        let gsa = GsaData::default();
+        assert_eq!(gsa.mode2, GsaMode2::NoFix);
+        assert!(gsa.fix_sats_prn.is_empty());
+        let gll = GllData::default();
+        assert_eq!(gll.latitude, 0.);
+        assert_eq!(gll.fix_time, NaiveTime::from_hms_opt(0, 0, 0).unwrap());
+
+        let gga = GgaData {
+            fix_satellites: Some(8),
+            ..GgaData::default()
+        };
+        assert_eq!(gga.fix_satellites, Some(8));
+    }
+
+    #[test]
+    fn test_gga_datetime_with_date() {
+        let gga = parse_gga(&NmeaSentence {
+            start_delimiter: b'$',
+            talker_id: b"GP",
+            message_id: b"GGA",
+            data: b"092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,",
+            checksum: 0x76,
+        })
+        .unwrap();
+        let date = NaiveDate::from_ymd_opt(2011, 5, 28).unwrap();
+        assert_eq!(
+            gga.datetime_with_date(date),
+            Some(Utc.from_utc_datetime(&date.and_hms_opt(9, 27, 50).unwrap()))
+        );
+
+        let no_time = GgaData {
+            fix_time: None,
+            ..gga
+        };
+        assert_eq!(no_time.datetime_with_date(date), None);
+    }
+
+    #[test]
+    fn test_parse_gga_dgps() {
+        let run_parse_gga = |line: &[u8]| -> GgaData {
+            let s = parse_nmea_sentence(line).unwrap();
+            assert_eq!(s.checksum, s.calc_checksum());
+            parse_gga(&s).unwrap()
+        };
+        let data = run_parse_gga(
+            b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,2,8,1.03,61.7,M,55.2,M,3.2,0120*59",
+        );
+        assert_eq!(data.fix_type, Some(FixType::DGps));
+        assert_eq!(data.age_of_dgps, Some(3.2));
+        assert_eq!(data.dgps_station_id, Some(120));
+
+        // out of range station id and age
+        let data = run_parse_gga(
+            b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,2,8,1.03,61.7,M,55.2,M,3.2,9999*5A",
+        );
+        assert_eq!(data.age_of_dgps, Some(3.2));
+        assert_eq!(data.dgps_station_id, None);
+        let data = run_parse_gga(
+            b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,2,8,1.03,61.7,M,55.2,M,-1,1023*69",
+        );
+        assert_eq!(data.age_of_dgps, None);
+        assert_eq!(data.dgps_station_id, Some(1023));
+        let data = run_parse_gga(
+            b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,2,8,1.03,61.7,M,55.2,M,99999,0001*4D",
+        );
+        assert_eq!(data.age_of_dgps, None);
+        assert_eq!(data.dgps_station_id, Some(1));
+    }
+
+    #[test]
+    fn test_parse_gga_non_finite_hdop() {
+        for line in &[
+            &b"$GPGGA,033745.0,5650.82344,N,03548.9778,E,1,07,nan,101.2,M,14.7,M,,*26"[..],
+            &b"$GPGGA,033745.0,5650.82344,N,03548.9778,E,1,07,inf,101.2,M,14.7,M,,*26"[..],
+        ] {
+            let s = parse_nmea_sentence(line).unwrap();
+            assert_eq!(s.checksum, s.calc_checksum());
+            let data = parse_gga(&s).unwrap();
+            assert_eq!(data.hdop, None);
+            assert_eq!(data.fix_satellites, Some(7));
+            assert_eq!(data.altitude, Some(101.2));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        for line in &[
+            &b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76"[..],
+            &b"$GPGSV,3,1,11,10,63,137,17,07,61,098,15,05,59,290,20,08,54,157,30*70"[..],
+            &b"$GPRMC,092750.000,A,5321.6802,N,00630.3372,W,0.02,31.66,280511,,,A*43"[..],
+            &b"$GPGSA,A,3,10,07,05,02,29,04,08,13,,,,,1.72,1.03,1.38*0A"[..],
+            &b"$GPAAM,,,,,*76"[..],
+        ] {
+            let json = serde_json::to_string(&parse(line).unwrap()).unwrap();
+            let back: ParseResult = serde_json::from_str(&json).unwrap();
+            assert_eq!(json, serde_json::to_string(&back).unwrap());
+        }
+        let gga = parse(b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76")
+            .unwrap();
+        let json: serde_json::Value = serde_json::to_value(&gga).unwrap();
+        assert_eq!(json["type"], "GGA");
+        assert_eq!(json["fix_satellites"], 8);
+    }
+
+    #[test]
+    fn test_sentence_fields() {
+        let s = NmeaSentence {
+            start_delimiter: b'$',
             talker_id: b"GP",
+            message_id: b"XXX",
+            data: b",,5,6,",
+            checksum: 0,
+        };
+        let fields: Vec<&str> = s.fields().map(|f| str::from_utf8(f).unwrap()).collect();
+        assert_eq!(fields, ["", "", "5", "6", ""]);
+
+        let s = parse_nmea_sentence(b"$HCHDM,238.5,M*25").unwrap();
+        assert_eq!(s.fields().collect::<Vec<_>>(), [&b"238.5"[..], b"M"]);
+    }
+
+    #[test]
+    fn test_sentence_id_str() {
+        let s = parse_nmea_sentence(b"$HCHDM,238.5,M*25").unwrap();
+        assert_eq!(s.talker_id_str(), Ok("HC"));
+        assert_eq!(s.message_id_str(), Ok("HDM"));
+
+        let s = NmeaSentence {
+            start_delimiter: b'$',
+            talker_id: b"\xffP",
             message_id: b"GGA",
-            data: b"033745.0,5650.82344,N,03548.9778,E,1,07,1.8,101.2,M,14.7,M,,",
-            checksum: 0x57,
-        })
+            data: b"",
+            checksum: 0,
+        };
+        assert!(s.talker_id_str().is_err());
+        assert_eq!(s.message_id_str(), Ok("GGA"));
+    }
+
+    #[test]
+    fn test_verify_checksum() {
+        let gga = b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76";
+        assert_eq!(verify_checksum(gga), Ok(true));
+        assert_eq!(
+            verify_checksum(
+                b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*77"
+            ),
+            Ok(false)
+        );
+        // payload isn't decoded, so broken GGA with valid checksum passes
+        assert_eq!(verify_checksum(b"$GPGGA,garbage*0F"), Ok(true));
+        assert!(verify_checksum(b"$GPGGA,092750.000,5321.6802,N").is_err());
+        assert!(verify_checksum(b"$GPGGA,092750.000*").is_err());
+        assert!(verify_checksum(b"").is_err());
+    }
+
+    #[test]
+    fn test_classify() {
+        for (line, expected) in &[
+            (
+                &b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76"[..],
+                Some((TalkerId::Gps, SentenceType::GGA)),
+            ),
+            // checksum and payload are not checked
+            (
+                b"$HCHDM,garbage*00",
+                Some((TalkerId::HeadingCompass, SentenceType::HDM)),
+            ),
+            (b"$GPXYZ,1*00", Some((TalkerId::Gps, SentenceType::None))),
+            (
+                b"!AIVDM,1,1,,A,13aEOK?P00PD2wVMdLDRhgvL289?,0*26",
+                Some((TalkerId::Ais('A', 'I'), SentenceType::VDM)),
+            ),
+            (
+                b"$PGRMZ,246,f,3*1B",
+                Some((TalkerId::Proprietary, SentenceType::None)),
+            ),
+            (
+                b"\\s:GPS1,c:1620000000*4C\\$GNRMC,",
+                Some((TalkerId::GnssCombined, SentenceType::RMC)),
+            ),
+            (b"$GPGGA", None),
+            (b"GPGGA,", None),
+            (b"\\s:GPS1,c:1620000000*4C$GPRMC,", None),
+            (b"", None),
+        ] {
+            assert_eq!(&classify(line), expected, "{:?}", line);
+        }
+    }
+
+    #[test]
+    fn test_is_complete_frame() {
+        for buf in &[
+            &b"$HCHDM,238.5,M*25\r\n"[..],
+            b"$HCHDM,238.5,M*25\n",
+            b"\r\n$HCHDM,238.5,M*25\r",
+            b"!AIVDM,1,1,,A,13aEOK?P00PD2wVMdLDRhgvL289?,0*26\r\n",
+            b"$HCHDM,238.5,M*25\r\n$GPGGA,0927",
+        ] {
+            assert!(is_complete_frame(buf), "{:?}", buf);
+        }
+        for buf in &[
+            &b""[..],
+            b"$HCHDM,238.5",
+            b"$HCHDM,238.5,M*",
+            b"$HCHDM,238.5,M*2",
+            b"$HCHDM,238.5,M*25",
+            b"$HCHDM,238.5,M*ZZ\r\n",
+            b"HCHDM,238.5,M*25\r\n",
+        ] {
+            assert!(!is_complete_frame(buf), "{:?}", buf);
+        }
+    }
+
+    #[test]
+    fn test_is_valid_nmea() {
+        assert!(is_valid_nmea(
+            b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76"
+        ));
+        assert!(is_valid_nmea(b"$PGRMZ,246,f,3*1B\r\n"));
+        assert!(!is_valid_nmea(
+            b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*77"
+        ));
+        assert!(!is_valid_nmea(b"$GPGGA,092750.000,5321.6802,N"));
+        assert!(!is_valid_nmea(b"garbage"));
+        assert!(!is_valid_nmea(b""));
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let buf = b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76\r\n\
+                    $GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*77\r\n\
+                    \r\n\
+                    $HCHDM,238.5\n\
+                    $HCHDM,238.5,M*25";
+        let results: Vec<_> = parse_lines(buf).collect();
+        assert_eq!(results.len(), 4);
+        assert!(matches!(results[0], Ok(ParseResult::GGA(_))));
+        assert!(matches!(
+            results[1],
+            Err(NmeaError::ChecksumMismatch { .. })
+        ));
+        assert!(results[2].is_err());
+        assert!(matches!(results[3], Ok(ParseResult::HDM(_))));
+        assert_eq!(parse_lines(b"\r\n\n").count(), 0);
+    }
+
+    #[test]
+    fn test_parse_str() {
+        let line = String::from("$HCHDM,238.5,M*25");
+        let s = parse_nmea_sentence_str(&line).unwrap();
+        assert_eq!(s.message_id, b"HDM");
+        assert_eq!(s.data, b"238.5,M");
+        match parse_str(&line) {
+            Ok(ParseResult::HDM(hdm)) => assert_eq!(hdm.heading, Some(238.5)),
+            _ => panic!("HDM expected"),
+        }
+        assert!(parse_str("$HCHDM,238.5,M*26").is_err());
+    }
+
+    #[test]
+    fn test_split_sentences() {
+        let buf = b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76\r\n\
+                    $GPRMC,092750.000,A,5321.6802,N,00630.3372,W,0.02,31.66,280511,,,A*43\r\n";
+        let mut gga = None;
+        let mut ids = Vec::new();
+        for sentence in split_sentences(buf) {
+            let sentence = sentence.unwrap();
+            ids.push(sentence.message_id);
+            if sentence.message_id == b"GGA" {
+                gga = Some(parse_gga(&sentence).unwrap());
+            }
+        }
+        assert_eq!(ids, [b"GGA", b"RMC"]);
+        assert_eq!(gga.unwrap().fix_satellites, Some(8));
+
+        let results: Vec<_> = split_sentences(b"$HCHDM,238.5,M*26\n$HCHDM,238.5").collect();
+        assert!(matches!(
+            results[0],
+            Err(NmeaError::ChecksumMismatch { .. })
+        ));
+        assert!(results[1].is_err());
+    }
+
+    #[test]
+    fn test_sentence_type() {
+        let s = parse_nmea_sentence(b"$HCHDM,238.5,M*25").unwrap();
+        assert_eq!(s.sentence_type(), Ok(SentenceType::HDM));
+        let s = parse_nmea_sentence(b"$PGRMZ,246,f,3*1B").unwrap();
+        assert_eq!(s.sentence_type(), Ok(SentenceType::None));
+        assert_eq!(s.message_id, b"RMZ");
+        let s = parse_nmea_sentence(b"$HC\xff\xff\xff,1*E9").unwrap();
+        assert_eq!(s.sentence_type(), Err(NmeaError::UnknownSentenceType));
+    }
+
+    #[test]
+    fn test_parse_start_delimiter() {
+        let s = parse_nmea_sentence(
+            b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76",
+        )
         .unwrap();
-        assert_eq!(data.fix_time.unwrap(), NaiveTime::from_hms(3, 37, 45));
-        assert_eq!(data.fix_type.unwrap(), FixType::Gps);
-        relative_eq!(data.latitude.unwrap(), 56. + 50.82344 / 60.);
-        relative_eq!(data.longitude.unwrap(), 35. + 48.9778 / 60.);
-        assert_eq!(data.fix_satellites.unwrap(), 7);
-        relative_eq!(data.hdop.unwrap(), 1.8);
-        relative_eq!(data.altitude.unwrap(), 101.2);
-        relative_eq!(data.geoid_height.unwrap(), 14.7);
+        assert_eq!(s.start_delimiter, b'$');
+        assert_eq!(s.message_id, b"GGA");
+        assert_eq!(s.checksum, s.calc_checksum());
 
-        let s = parse_nmea_sentence(b"$GPGGA,,,,,,0,,,,,,,,*66").unwrap();
+        let line = b"!AIVDM,1,1,,A,13aGmP0P00PD;88MD5MTDww@2<0L,0*23";
+        let s = parse_nmea_sentence(line).unwrap();
+        assert_eq!(s.start_delimiter, b'!');
+        assert_eq!(s.talker(), TalkerId::Ais('A', 'I'));
+        assert_eq!(s.message_id, b"VDM");
+        assert_eq!(s.data, &b"1,1,,A,13aGmP0P00PD;88MD5MTDww@2<0L,0"[..]);
         assert_eq!(s.checksum, s.calc_checksum());
-        let data = parse_gga(&s).unwrap();
+        assert_eq!(s.to_owned().as_sentence().start_delimiter, b'!');
+        assert!(is_valid_nmea(line));
+        assert!(matches!(
+            parse(line),
+            Ok(ParseResult::Unsupported {
+                talker: TalkerId::Ais('A', 'I'),
+                sentence: SentenceType::VDM
+            })
+        ));
+        // not decoded as usual sentence even it has known type
+        assert!(matches!(
+            parse(b"!GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76"),
+            Ok(ParseResult::Unsupported {
+                talker: TalkerId::Gps,
+                sentence: SentenceType::GGA
+            })
+        ));
+        assert!(parse_nmea_sentence(b"#GPGGA,*56").is_err());
+    }
+
+    #[test]
+    fn test_parse_tag_block() {
+        let line = b"\\s:GPS1,c:1620000000*4C\\$GPRMC,092750.000,A,5321.6802,N,00630.3372,W,0.02,31.66,280511,,,A*43";
+        let (tag_block, sentence) = parse_tag_block(line).unwrap();
+        assert_eq!(tag_block.source, Some(&b"GPS1"[..]));
+        assert_eq!(tag_block.unix_time, Some(1_620_000_000));
+        assert_eq!(tag_block.group, None);
+        assert!(sentence.starts_with(b"$GPRMC,"));
+        assert!(matches!(parse(line), Ok(ParseResult::RMC(_))));
+        assert!(matches!(
+            parse_full(line),
+            Ok((TalkerId::Gps, ParseResult::RMC(_)))
+        ));
+
+        let (tag_block, sentence) =
+            parse_tag_block(b"\\g:1-2-42,s:r3669961,c:1620000000*0B\\$PGRMZ,246,f,3*1B").unwrap();
+        assert_eq!(tag_block.group, Some(&b"1-2-42"[..]));
+        assert_eq!(tag_block.source, Some(&b"r3669961"[..]));
+        assert_eq!(sentence, b"$PGRMZ,246,f,3*1B");
+
         assert_eq!(
-            GgaData {
-                fix_time: None,
-                fix_type: Some(FixType::Invalid),
-                latitude: None,
-                longitude: None,
-                fix_satellites: None,
-                hdop: None,
-                altitude: None,
-                geoid_height: None,
-            },
-            data
+            parse_tag_block(b"\\s:GPS1,c:1620000000*4D\\$HCHDM,238.5,M*25").err(),
+            Some(NmeaError::ChecksumMismatch {
+                expected: 0x4C,
+                found: 0x4D
+            })
+        );
+        assert_eq!(
+            parse_tag_block(b"\\s:GPS1,c:16200x0000*04\\$HCHDM,238.5,M*25").err(),
+            Some(NmeaError::InvalidField("TAG block time"))
+        );
+        assert!(parse_tag_block(b"\\s:GPS1,c:1620000000*4C$HCHDM,238.5,M*25").is_err());
+        assert!(parse(b"\\s:GPS1*00\\$HCHDM,238.5,M*25").is_err());
+    }
+
+    #[test]
+    fn test_parse_proprietary_sentence() {
+        let s = parse_proprietary_sentence(b"$PGRMZ,246,f,3*1B").unwrap();
+        assert_eq!(
+            s,
+            ProprietarySentence {
+                manufacturer: *b"GRM",
+                data: b"Z,246,f,3",
+                checksum: 0x1B,
+            }
+        );
+        assert_eq!(s.calc_checksum(), s.checksum);
+
+        // address is shorter than usual 5 chars
+        let line = b"$PUBX,40,GLL,1,0,0,0,0,0*5D";
+        let s = parse_proprietary_sentence(line).unwrap();
+        assert_eq!(&s.manufacturer, b"UBX");
+        assert_eq!(s.data, b",40,GLL,1,0,0,0,0,0");
+        assert!(parse_nmea_sentence(line).is_err());
+        assert!(matches!(
+            parse(line),
+            Ok(ParseResult::Unsupported {
+                talker: TalkerId::Proprietary,
+                sentence: SentenceType::None
+            })
+        ));
+        assert!(matches!(
+            parse_full(b"$PGRMZ,246,f,3*1B"),
+            Ok((
+                TalkerId::Proprietary,
+                ParseResult::Unsupported {
+                    sentence: SentenceType::None,
+                    ..
+                }
+            ))
+        ));
+        assert!(matches!(
+            parse(b"$PGRMZ,246,f,3*1C"),
+            Err(NmeaError::ChecksumMismatch { .. })
+        ));
+        assert!(parse_proprietary_sentence(b"$GPGGA,*56").is_err());
+        assert!(parse_proprietary_sentence(b"$PG,*56").is_err());
+    }
+
+    #[test]
+    fn test_parse_skip_invalid_fix() {
+        let skip = ParseOptions {
+            skip_invalid_fix: true,
+            ..ParseOptions::default()
+        };
+        let line = b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,0,8,1.03,61.7,M,55.2,M,,*77";
+        match parse(line) {
+            Ok(ParseResult::GGA(data)) => assert_eq!(data.fix_type, Some(FixType::Invalid)),
+            _ => panic!("GGA without fix should be parsed by default"),
+        }
+        assert_eq!(
+            parse_with_options(line, &skip).err().unwrap(),
+            NmeaError::NoFix
+        );
+
+        let line = b"$GPRMC,225446.33,V,4916.45,N,12311.12,W,000.5,054.7,191194,020.3,E*51";
+        assert!(matches!(parse(line), Ok(ParseResult::RMC(_))));
+        assert_eq!(
+            parse_with_options(line, &skip).err().unwrap(),
+            NmeaError::NoFix
+        );
+        let line = b"$GPGSA,A,1,,,,,,,,,,,,,,,*1E";
+        assert_eq!(
+            parse_with_options(line, &skip).err().unwrap(),
+            NmeaError::NoFix
+        );
+
+        // sentences with fix and without fix status are not affected
+        for line in &[
+            &b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76"[..],
+            b"$HCHDM,238.5,M*25",
+        ] {
+            assert!(parse_with_options(line, &skip).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_parse_result_accessors() {
+        let gga =
+            parse_str("$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76")
+                .unwrap();
+        assert_eq!(gga.sentence_type(), SentenceType::GGA);
+        assert!(gga.is_position());
+        assert!(format!("{:?}", gga).starts_with("GGA(GgaData {"));
+
+        let hdm = parse_str("$HCHDM,238.5,M*25").unwrap();
+        assert_eq!(hdm.sentence_type(), SentenceType::HDM);
+        assert!(!hdm.is_position());
+
+        let unsupported = parse_str("$GPDTM,W84,,0.0,N,0.0,E,0.0,W84*6F").unwrap();
+        assert_eq!(unsupported.sentence_type(), SentenceType::DTM);
+        assert!(!unsupported.is_position());
+        assert_eq!(
+            format!("{:?}", unsupported),
+            "Unsupported { talker: Gps, sentence: DTM }"
+        );
+    }
+
+    #[test]
+    fn test_try_from_sentence() {
+        fn fix_satellites(line: &[u8]) -> Result<Option<u32>, NmeaError> {
+            let sentence = parse_nmea_sentence(line)?;
+            Ok(GgaData::try_from(&sentence)?.fix_satellites)
+        }
+
+        assert_eq!(
+            fix_satellites(
+                b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76"
+            ),
+            Ok(Some(8))
         );
+        assert_eq!(
+            fix_satellites(b"$GPVTG,360.0,T,348.7,M,000.0,N,000.0,K*43"),
+            Err(NmeaError::UnexpectedSentence {
+                expected: SentenceType::GGA,
+                got: *b"VTG",
+            })
+        );
+
+        let sentence = parse_nmea_sentence(
+            b"$GPRMC,092750.000,A,5321.6802,N,00630.3372,W,0.02,31.66,280511,,,A*43",
+        )
+        .unwrap();
+        let rmc = RmcData::try_from(&sentence).unwrap();
+        assert_eq!(rmc.speed_over_ground, Some(0.02));
+        let sentence =
+            parse_nmea_sentence(b"$GPGSA,A,3,23,31,22,16,03,07,,,,,,,1.8,1.1,1.4*3E").unwrap();
+        assert_eq!(GsaData::try_from(&sentence).unwrap().fix_sats_prn.len(), 6);
+    }
+
+    #[test]
+    fn test_normalize_heading() {
+        assert_eq!(normalize_heading(360.), 0.);
+        assert_eq!(normalize_heading(-0.5), 359.5);
+        assert_eq!(normalize_heading(54.7), 54.7);
+
+        let rmc: RmcData = "$GPRMC,092750.000,A,5321.6802,N,00630.3372,W,0.02,-0.5,280511,,,A*69"
+            .parse()
+            .unwrap();
+        assert_eq!(rmc.true_course, Some(359.5));
+        match parse(b"$HCHDM,360.0,M*2C") {
+            Ok(ParseResult::HDM(hdm)) => assert_eq!(hdm.heading, Some(0.)),
+            result => panic!("HDM expected, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_speed_over_ground_mps() {
+        let rmc = RmcData {
+            speed_over_ground: Some(10.),
+            ..RmcData::default()
+        };
+        assert!(relative_eq!(
+            rmc.speed_over_ground_mps().unwrap(),
+            5.1444,
+            epsilon = 1e-4
+        ));
+        assert_eq!(RmcData::default().speed_over_ground_mps(), None);
+
+        let vtg: VtgData = "$GPVTG,054.7,T,034.4,M,005.5,N,010.2,K*48".parse().unwrap();
+        assert!(relative_eq!(
+            vtg.speed_over_ground_mps().unwrap(),
+            5.5 * 1852. / 3600.,
+            epsilon = 1e-4
+        ));
+        assert_eq!(VtgData::default().speed_over_ground_mps(), None);
+    }
+
+    #[test]
+    fn test_from_str() {
+        let gga: GgaData = "$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76"
+            .parse()
+            .unwrap();
+        assert_eq!(gga.fix_satellites, Some(8));
+        let rmc: RmcData = "$GPRMC,092750.000,A,5321.6802,N,00630.3372,W,0.02,31.66,280511,,,A*43"
+            .parse()
+            .unwrap();
+        assert_eq!(rmc.speed_over_ground, Some(0.02));
+        let vtg: VtgData = "$GPVTG,360.0,T,348.7,M,000.0,N,000.0,K*43".parse().unwrap();
+        assert_eq!(vtg.true_course, Some(0.));
+        let gll: GllData = "$GPGLL,5107.0013414,N,11402.3279144,W,205412.00,A,A*73"
+            .parse()
+            .unwrap();
+        assert_eq!(gll.fix_time, NaiveTime::from_hms_opt(20, 54, 12).unwrap());
+        let gsa: GsaData = "$GPGSA,A,3,23,31,22,16,03,07,,,,,,,1.8,1.1,1.4*3E"
+            .parse()
+            .unwrap();
+        assert_eq!(gsa.fix_sats_prn.len(), 6);
+        let gsv: GsvData = "$GPGSV,3,1,12,01,49,196,41,03,71,278,32,06,02,323,27,11,21,196,39*72"
+            .parse()
+            .unwrap();
+        assert_eq!(gsv.sats_info.len(), 4);
+
+        assert_eq!(
+            "$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76"
+                .parse::<RmcData>(),
+            Err(NmeaError::UnexpectedSentence {
+                expected: SentenceType::RMC,
+                got: *b"GGA",
+            })
+        );
+        assert_eq!(
+            "$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*77"
+                .parse::<GgaData>(),
+            Err(NmeaError::ChecksumMismatch {
+                expected: 0x76,
+                found: 0x77,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_decimal_comma() {
+        let options = ParseOptions {
+            decimal_comma: true,
+            ..ParseOptions::default()
+        };
+        let line = b"$GPGGA,092750,000,5321,6802,N,00630,3372,W,1,8,1,03,61,7,M,55,2,M,,*76";
+        assert!(parse(line).is_err());
+        match parse_with_options(line, &options) {
+            Ok(ParseResult::GGA(gga)) => {
+                assert_eq!(gga.fix_time, NaiveTime::from_hms_opt(9, 27, 50));
+                assert!(relative_eq!(gga.latitude.unwrap(), 53. + 21.6802 / 60.));
+                assert!(relative_eq!(gga.longitude.unwrap(), -(6. + 30.3372 / 60.)));
+                assert_eq!(gga.fix_satellites, Some(8));
+                assert_eq!(gga.hdop, Some(1.03));
+                assert_eq!(gga.altitude, Some(61.7));
+                assert_eq!(gga.geoid_height, Some(55.2));
+            }
+            _ => panic!("GGA with decimal comma expected"),
+        }
+
+        // only some fields use comma
+        let line = b"$HCHDM,238,5,M*27";
+        match parse_with_options(line, &options) {
+            Ok(ParseResult::HDM(hdm)) => assert_eq!(hdm.heading, Some(238.5)),
+            _ => panic!("HDM with decimal comma expected"),
+        }
+
+        // NMEA 2.3 RMC without navigational status
+        let line = b"$GPRMC,092750.000,A,5321.6802,N,00630.3372,W,0,02,31.66,280511,,,A*41";
+        match parse_with_options(line, &options) {
+            Ok(ParseResult::RMC(rmc)) => {
+                assert_eq!(rmc.speed_over_ground, Some(0.02));
+                assert_eq!(rmc.true_course, Some(31.66));
+                assert_eq!(rmc.fix_date, NaiveDate::from_ymd_opt(2011, 5, 28));
+            }
+            _ => panic!("RMC with decimal comma expected"),
+        }
+
+        // VTG before NMEA 2.3, without FAA mode
+        let line = b"$GPVTG,054,7,T,034,4,M,005,5,N,010,2,K*48";
+        match parse_with_options(line, &options) {
+            Ok(ParseResult::VTG(vtg)) => {
+                assert_eq!(vtg.true_course, Some(54.7));
+                assert_eq!(vtg.magnetic_course, Some(34.4));
+                assert_eq!(vtg.speed_over_ground, Some(5.5));
+            }
+            _ => panic!("VTG with decimal comma expected"),
+        }
+
+        // usual sentence is not touched
+        let line = b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76";
+        assert!(matches!(
+            (parse(line), parse_with_options(line, &options)),
+            (Ok(ParseResult::GGA(a)), Ok(ParseResult::GGA(b))) if a == b
+        ));
+
+        // ambiguous: 1.2 and 3 or 1 and 2.3 for HDOP and altitude
+        let s = parse_nmea_sentence(b"$GPGGA,092750,5321,N,00630,W,1,8,1,2,3,M,55,M,,*63").unwrap();
+        assert!(normalize_decimal_comma(&s, SentenceType::GGA).is_none());
+    }
+
+    #[test]
+    fn test_parse_lenient_truncated() {
+        let lenient = ParseOptions {
+            lenient: true,
+            ..ParseOptions::default()
+        };
+        let line = b"$GPGGA,033745.0,5650.82344,N,03548.9778,E,1,07*5B";
+        assert!(parse(line).is_err());
+        match parse_with_options(line, &lenient) {
+            Ok(ParseResult::GGA(data)) => {
+                assert_eq!(data.fix_type, Some(FixType::Gps));
+                assert_eq!(data.fix_satellites, Some(7));
+                assert_eq!(data.hdop, None);
+                assert_eq!(data.altitude, None);
+                assert_eq!(data.geoid_height, None);
+            }
+            _ => panic!("lenient GGA parse failed"),
+        }
+
+        let line = b"$GPRMC,225446.33,A,4916.45,N,12311.12,W,000.5*01";
+        assert!(parse(line).is_err());
+        match parse_with_options(line, &lenient) {
+            Ok(ParseResult::RMC(data)) => {
+                assert_eq!(data.speed_over_ground, Some(0.5));
+                assert_eq!(data.true_course, None);
+                assert_eq!(data.fix_date, None);
+            }
+            _ => panic!("lenient RMC parse failed"),
+        }
+
+        // complete sentence parsed same way in both modes
+        let line = b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76";
+        match (parse(line), parse_with_options(line, &lenient)) {
+            (Ok(ParseResult::GGA(strict)), Ok(ParseResult::GGA(lenient))) => {
+                assert_eq!(strict, lenient)
+            }
+            _ => panic!("GGA parse failed"),
+        }
+    }
+
+    #[test]
+    fn test_owned_nmea_sentence() {
+        let line = b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76";
+        let owned = parse_nmea_sentence(line).unwrap().to_owned();
+        assert_eq!(&owned.talker_id, b"GP");
+        assert_eq!(&owned.message_id, b"GGA");
+        assert_eq!(owned.checksum, 0x76);
+
+        let s = owned.as_sentence();
+        let orig = parse_nmea_sentence(line).unwrap();
+        assert_eq!(s.talker_id, orig.talker_id);
+        assert_eq!(s.message_id, orig.message_id);
+        assert_eq!(s.data, orig.data);
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(parse_gga(&s).unwrap(), parse_gga(&orig).unwrap());
     }
 
     #[test]
@@ -841,6 +3839,35 @@ mod tests {
         assert_eq!(sentence.checksum, 0x4f);
         let data = parse_gga(&sentence).unwrap();
         assert_eq!(data.fix_type.unwrap(), FixType::Invalid);
+        assert_eq!(data.altitude, None);
+        assert_eq!(data.geoid_height, None);
+
+        // empty altitude with `M` unit markers and valid fix
+        for line in &[
+            &b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,,M,,M,,*76"[..],
+            b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,,M,55.2,M,,*76",
+            b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,,,,,,*76",
+        ] {
+            let data = parse_gga(&parse_nmea_sentence(line).unwrap()).unwrap();
+            assert_eq!(data.altitude, None);
+            assert_eq!(data.hdop, Some(1.03));
+            assert_eq!(data.fix_satellites, Some(8));
+        }
+        let data = parse_gga(
+            &parse_nmea_sentence(
+                b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,,M,55.2,M,,*76",
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(data.geoid_height, Some(55.2));
+
+        // missing altitude field shifts `M` into its place
+        assert!(parse_gga(
+            &parse_nmea_sentence(b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,M,,M,,*76")
+                .unwrap()
+        )
+        .is_err());
     }
 
     #[test]
@@ -855,9 +3882,12 @@ mod tests {
         let rmc_data = parse_rmc(&s).unwrap();
         assert_eq!(
             rmc_data.fix_time.unwrap(),
-            NaiveTime::from_hms_milli(22, 54, 46, 330)
+            NaiveTime::from_hms_milli_opt(22, 54, 46, 330).unwrap()
+        );
+        assert_eq!(
+            rmc_data.fix_date.unwrap(),
+            NaiveDate::from_ymd_opt(1994, 11, 19).unwrap()
         );
-        assert_eq!(rmc_data.fix_date.unwrap(), NaiveDate::from_ymd(94, 11, 19));
 
         println!("lat: {}", rmc_data.lat.unwrap());
         relative_eq!(rmc_data.lat.unwrap(), 49.0 + 16.45 / 60.);
@@ -871,77 +3901,200 @@ mod tests {
         relative_eq!(rmc_data.speed_over_ground.unwrap(), 0.5);
         relative_eq!(rmc_data.true_course.unwrap(), 54.7);
 
-        let s = parse_nmea_sentence(b"$GPRMC,,V,,,,,,,,,,N*53").unwrap();
+        assert_eq!(
+            rmc_data.datetime(),
+            NaiveDate::from_ymd_opt(1994, 11, 19)
+                .unwrap()
+                .and_hms_milli_opt(22, 54, 46, 330)
+        );
+        assert_eq!(
+            rmc_data.datetime_utc().unwrap().naive_utc(),
+            rmc_data.datetime().unwrap()
+        );
+
+        let tz = chrono::FixedOffset::east_opt(3 * 3600).unwrap();
+        let local = rmc_data.local_datetime(&tz).unwrap();
+        assert_eq!(
+            local.naive_local(),
+            NaiveDate::from_ymd_opt(1994, 11, 20)
+                .unwrap()
+                .and_hms_milli_opt(1, 54, 46, 330)
+                .unwrap()
+        );
+        assert_eq!(local.naive_utc(), rmc_data.datetime().unwrap());
+
+        let s = parse_nmea_sentence(b"$GPRMC,,V,,,,,,,,,,N*53").unwrap();
+        let rmc = parse_rmc(&s).unwrap();
+        assert_eq!(
+            RmcData {
+                fix_time: None,
+                fix_date: None,
+                status_of_fix: Some(RmcStatusOfFix::Invalid),
+                lat: None,
+                lon: None,
+                speed_over_ground: None,
+                true_course: None,
+                magnetic_variation: None,
+                nav_status: None,
+            },
+            rmc
+        );
+        assert_eq!(rmc.datetime(), None);
+        assert_eq!(rmc.local_datetime(&Utc), None);
+        let time_only = RmcData {
+            fix_time: NaiveTime::from_hms_opt(22, 54, 46),
+            ..rmc
+        };
+        assert_eq!(time_only.datetime(), None);
+
+        let s = parse_nmea_sentence(
+            b"$GPRMC,092750.000,A,5321.6802,N,00630.3372,W,0.02,31.66,280511,,,A*43",
+        )
+        .unwrap();
+        let rmc = parse_rmc(&s).unwrap();
+        assert_eq!(
+            rmc.datetime_utc().map(|t| t.to_rfc3339()),
+            Some("2011-05-28T09:27:50+00:00".into())
+        );
+    }
+
+    #[test]
+    fn test_parse_date_century() {
+        use chrono::Datelike;
+
+        for (date, year) in [
+            (&b"280511"[..], 2011),
+            (b"010100", 2000),
+            (b"311279", 2079),
+            (b"010180", 1980),
+            (b"311299", 1999),
+        ]
+        .iter()
+        {
+            let (_, date) = parse_date(date).unwrap();
+            assert_eq!(date.year(), *year);
+        }
+    }
+
+    #[test]
+    fn test_rmc_magnetic_course() {
+        let s = parse_nmea_sentence(
+            b"$GPRMC,225446,A,4916.45,N,12311.12,W,000.5,054.7,191194,020.3,E*68",
+        )
+        .unwrap();
+        let rmc = parse_rmc(&s).unwrap();
+        assert_eq!(rmc.magnetic_variation, Some(20.3));
+        assert!(relative_eq!(rmc.magnetic_course().unwrap(), 34.4));
+
+        let s = parse_nmea_sentence(
+            b"$GPRMC,225446,A,4916.45,N,12311.12,W,000.5,354.7,191194,020.3,W*79",
+        )
+        .unwrap();
+        let rmc = parse_rmc(&s).unwrap();
+        assert_eq!(rmc.magnetic_variation, Some(-20.3));
+        assert!(relative_eq!(rmc.magnetic_course().unwrap(), 15.0));
+
+        let rmc = RmcData {
+            true_course: Some(5.),
+            magnetic_variation: Some(12.5),
+            ..rmc
+        };
+        assert!(relative_eq!(rmc.magnetic_course().unwrap(), 352.5));
+
+        let s = parse_nmea_sentence(
+            b"$GPRMC,092750.000,A,5321.6802,N,00630.3372,W,0.02,31.66,280511,,,A*43",
+        )
+        .unwrap();
+        let rmc = parse_rmc(&s).unwrap();
+        assert_eq!(rmc.magnetic_variation, None);
+        assert_eq!(rmc.magnetic_course(), None);
+    }
+
+    #[test]
+    fn test_parse_rmc_nav_status() {
+        let s = parse_nmea_sentence(
+            b"$GNRMC,225446.33,A,4916.45,N,12311.12,W,000.5,054.7,191194,020.3,E,A,S*4A",
+        )
+        .unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let rmc = parse_rmc(&s).unwrap();
+        assert_eq!(rmc.nav_status, Some('S'));
+        assert!(relative_eq!(rmc.true_course.unwrap(), 54.7));
+
+        let s = parse_nmea_sentence(
+            b"$GNRMC,001031.00,A,4404.13993,N,12118.86023,W,0.146,,100117,,,A,V*01",
+        )
+        .unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
         let rmc = parse_rmc(&s).unwrap();
-        assert_eq!(
-            RmcData {
-                fix_time: None,
-                fix_date: None,
-                status_of_fix: Some(RmcStatusOfFix::Invalid),
-                lat: None,
-                lon: None,
-                speed_over_ground: None,
-                true_course: None,
-            },
-            rmc
-        );
+        assert_eq!(rmc.nav_status, Some('V'));
+        assert_eq!(rmc.true_course, None);
+
+        let s = parse_nmea_sentence(
+            b"$GPRMC,171724.000,A,6847.2474,N,03245.8351,E,0.26,140.74,250317,,*02",
+        )
+        .unwrap();
+        assert_eq!(parse_rmc(&s).unwrap().nav_status, None);
     }
 
     #[test]
     fn test_parse_gsv_full() {
         let data = parse_gsv(&NmeaSentence {
+            start_delimiter: b'$',
             talker_id: b"GP",
             message_id: b"GSV",
             data: b"2,1,08,01,,083,46,02,17,308,,12,07,344,39,14,22,228,",
             checksum: 0,
         })
         .unwrap();
-        assert_eq!(data.gnss_type, GnssType::Gps);
-        assert_eq!(data.number_of_sentences, 2);
-        assert_eq!(data.sentence_num, 1);
-        assert_eq!(data._sats_in_view, 8);
-        assert_eq!(
-            data.sats_info[0].clone().unwrap(),
-            Satellite {
-                gnss_type: data.gnss_type.clone(),
-                prn: 1,
-                elevation: None,
-                azimuth: Some(83.),
-                snr: Some(46.),
-            }
-        );
-        assert_eq!(
-            data.sats_info[1].clone().unwrap(),
-            Satellite {
-                gnss_type: data.gnss_type.clone(),
-                prn: 2,
-                elevation: Some(17.),
-                azimuth: Some(308.),
-                snr: None,
-            }
-        );
-        assert_eq!(
-            data.sats_info[2].clone().unwrap(),
-            Satellite {
-                gnss_type: data.gnss_type.clone(),
-                prn: 12,
-                elevation: Some(7.),
-                azimuth: Some(344.),
-                snr: Some(39.),
-            }
-        );
         assert_eq!(
-            data.sats_info[3].clone().unwrap(),
-            Satellite {
-                gnss_type: data.gnss_type.clone(),
-                prn: 14,
-                elevation: Some(22.),
-                azimuth: Some(228.),
-                snr: None,
+            data,
+            GsvData {
+                gnss_type: GnssType::Gps,
+                number_of_sentences: 2,
+                sentence_num: 1,
+                sats_in_view: 8,
+                sats_info: [
+                    Some(Satellite {
+                        gnss_type: GnssType::Gps,
+                        prn: 1,
+                        elevation: None,
+                        azimuth: Some(83.),
+                        snr: Some(46.),
+                        used_in_fix: false,
+                    }),
+                    Some(Satellite {
+                        gnss_type: GnssType::Gps,
+                        prn: 2,
+                        elevation: Some(17.),
+                        azimuth: Some(308.),
+                        snr: None,
+                        used_in_fix: false,
+                    }),
+                    Some(Satellite {
+                        gnss_type: GnssType::Gps,
+                        prn: 12,
+                        elevation: Some(7.),
+                        azimuth: Some(344.),
+                        snr: Some(39.),
+                        used_in_fix: false,
+                    }),
+                    Some(Satellite {
+                        gnss_type: GnssType::Gps,
+                        prn: 14,
+                        elevation: Some(22.),
+                        azimuth: Some(228.),
+                        snr: None,
+                        used_in_fix: false,
+                    }),
+                ],
+                signal_id: None,
             }
         );
+        assert_eq!(data.tracked_count(), 2);
 
         let data = parse_gsv(&NmeaSentence {
+            start_delimiter: b'$',
             talker_id: b"GL",
             message_id: b"GSV",
             data: b"3,3,10,72,40,075,43,87,00,000,",
@@ -951,7 +4104,39 @@ mod tests {
         assert_eq!(data.gnss_type, GnssType::Glonass);
         assert_eq!(data.number_of_sentences, 3);
         assert_eq!(data.sentence_num, 3);
-        assert_eq!(data._sats_in_view, 10);
+        assert_eq!(data.sats_in_view, 10);
+        assert_eq!(data.tracked_count(), 1);
+    }
+
+    #[test]
+    fn test_gsv_signal_id() {
+        for &(data, id) in &[
+            (&b"1,1,00"[..], None),
+            (b"1,1,00,1", Some(1)),
+            (b"1,1,01,05,40,083,46", None),
+            (b"1,1,01,05,40,083,46,B", Some(11)),
+            (
+                b"3,1,12,01,49,196,41,03,71,278,32,06,02,323,27,11,21,196,39,7",
+                Some(7),
+            ),
+            (b"1,1,01,05,40,083,,", None),
+        ] {
+            assert_eq!(gsv_signal_id(data), id, "{:?}", data);
+        }
+    }
+
+    #[test]
+    fn test_parse_gsv_no_satellites() {
+        for line in &[&b"$GPGSV,1,1,00*79"[..], &b"$GLGSV,1,1,00,,,,*65"[..]] {
+            let s = parse_nmea_sentence(line).unwrap();
+            assert_eq!(s.checksum, s.calc_checksum());
+            let data = parse_gsv(&s).unwrap();
+            assert_eq!(data.number_of_sentences, 1);
+            assert_eq!(data.sentence_num, 1);
+            assert_eq!(data.sats_in_view, 0);
+            assert!(data.sats_info.iter().all(Option::is_none));
+            assert_eq!(data.tracked_count(), 0);
+        }
     }
 
     #[test]
@@ -966,18 +4151,34 @@ mod tests {
         assert_eq!(time.hour(), 12);
         assert_eq!(time.minute(), 56);
         assert_eq!(time.second(), 19);
-        assert_eq!(time.nanosecond(), 5_00_000_000);
+        assert_eq!(time.nanosecond(), 500_000_000);
+        let (_, time) = parse_hms(b"000000,").unwrap();
+        assert_eq!(time, NaiveTime::from_hms_opt(0, 0, 0).unwrap());
+
+        // too short time fields
+        for field in &[&b"1256,"[..], b"12561,", b"12561.5,", b"1256.19,", b","] {
+            assert!(parse_hms(field).is_err(), "{:?}", field);
+        }
+        let s = parse_nmea_sentence(
+            b"$GPGGA,1256,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*61",
+        )
+        .unwrap();
+        assert!(parse_gga(&s).is_err());
     }
 
     #[test]
     fn test_gsa_prn_fields_parse() {
-        let (_, ret) = gsa_prn_fields_parse(b"5,").unwrap();
-        assert_eq!(vec![Some(5)], ret);
-        let (_, ret) = gsa_prn_fields_parse(b",").unwrap();
-        assert_eq!(vec![None], ret);
+        let (_, ret) = gsa_prn_fields_parse(b"5,", MAX_GSA_PRNS).unwrap();
+        assert_eq!(vec![5], ret);
+        let (rest, ret) = gsa_prn_fields_parse(b",", MAX_GSA_PRNS).unwrap();
+        assert!(ret.is_empty());
+        assert!(rest.is_empty());
 
-        let (_, ret) = gsa_prn_fields_parse(b",,5,6,").unwrap();
-        assert_eq!(vec![None, None, Some(5), Some(6)], ret);
+        let (_, ret) = gsa_prn_fields_parse(b",,5,6,", MAX_GSA_PRNS).unwrap();
+        assert_eq!(vec![5, 6], ret);
+        let (rest, ret) = gsa_prn_fields_parse(b",,5,6,7,1.8", 2).unwrap();
+        assert_eq!(vec![5, 6], ret);
+        assert_eq!(rest, b"1.8");
     }
 
     #[test]
@@ -986,6 +4187,7 @@ mod tests {
         let gsa = parse_gsa(&s).unwrap();
         assert_eq!(
             GsaData {
+                gnss_type: Some(GnssType::Gps),
                 mode1: GsaMode1::Automatic,
                 mode2: GsaMode2::Fix3D,
                 fix_sats_prn: vec![16, 18, 22, 24],
@@ -1010,9 +4212,127 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_gsa_satellites_used() {
+        let s = parse_nmea_sentence(b"$GPGSA,A,3,19,28,14,19,27,28,,,,,,,1.7,1.0,1.3*37").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let gsa = parse_gsa(&s).unwrap();
+        assert_eq!(gsa.fix_sats_prn, vec![19, 28, 14, 27]);
+        assert_eq!(gsa.satellites_used(), 4);
+
+        let s = parse_nmea_sentence(b"$GPGSA,A,1,,,,,,,,,,,,,,,*1E").unwrap();
+        assert_eq!(parse_gsa(&s).unwrap().satellites_used(), 0);
+    }
+
+    #[test]
+    fn test_gsa_prns_with_gnss() {
+        let s = parse_nmea_sentence(b"$GNGSA,A,3,05,40,75,86,207,,,,,,,,1.8,1.0,1.5*18").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        assert_eq!(
+            parse_gsa(&s).unwrap().prns_with_gnss(),
+            vec![
+                (5, Some(GnssType::Gps)),
+                (40, Some(GnssType::Sbas)),
+                (75, Some(GnssType::Glonass)),
+                (86, Some(GnssType::Glonass)),
+                (207, Some(GnssType::Beidou)),
+            ]
+        );
+
+        let s = parse_nmea_sentence(b"$GNGSA,A,3,31,26,21,,,,,,,,,,3.77,2.55,2.77,1*07").unwrap();
+        assert_eq!(
+            parse_gsa(&s).unwrap().prns_with_gnss(),
+            vec![
+                (31, Some(GnssType::Gps)),
+                (26, Some(GnssType::Gps)),
+                (21, Some(GnssType::Gps)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_gsa_non_finite_dop() {
+        let s = parse_nmea_sentence(b"$GPGSA,A,3,31,26,21,,,,,,,,,,NaN,2.55,inf*05").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let gsa = parse_gsa(&s).unwrap();
+        assert_eq!(gsa.fix_sats_prn, vec![31, 26, 21]);
+        assert_eq!(gsa.pdop, None);
+        assert_eq!(gsa.hdop, Some(2.55));
+        assert_eq!(gsa.vdop, None);
+    }
+
+    #[test]
+    fn test_parse_gsa_system_id() {
+        let s = parse_nmea_sentence(b"$GNGSA,A,3,31,26,21,,,,,,,,,,3.77,2.55,2.77,1*07").unwrap();
+        assert_eq!(s.checksum, s.calc_checksum());
+        let gsa = parse_gsa(&s).unwrap();
+        assert_eq!(gsa.gnss_type, Some(GnssType::Gps));
+        assert_eq!(gsa.vdop, Some(2.77));
+
+        let s = parse_nmea_sentence(b"$GNGSA,A,3,75,86,87,,,,,,,,,,3.77,2.55,2.77,2*02").unwrap();
+        assert_eq!(parse_gsa(&s).unwrap().gnss_type, Some(GnssType::Glonass));
+
+        let s = parse_nmea_sentence(b"$GNGSA,A,3,75,86,87,,,,,,,,,,3.77,2.55,2.77*1C").unwrap();
+        assert_eq!(parse_gsa(&s).unwrap().gnss_type, None);
+
+        let s = parse_nmea_sentence(b"$GQGSA,A,3,193,194,,,,,,,,,,,3.77,2.55,2.77*07").unwrap();
+        assert_eq!(parse_gsa(&s).unwrap().gnss_type, Some(GnssType::Qzss));
+    }
+
+    #[test]
+    fn test_parse_gsa_prns_cap() {
+        // longer than sentence length limit, so not from `parse_nmea_sentence`
+        let prns: Vec<String> = (1..=1000).map(|prn| prn.to_string()).collect();
+        let data = format!("A,3,{},,1.8,1.1,1.4", prns.join(","));
+        let s = NmeaSentence {
+            start_delimiter: b'$',
+            talker_id: b"GP",
+            message_id: b"GSA",
+            data: data.as_bytes(),
+            checksum: 0,
+        };
+        let gsa = parse_gsa(&s).unwrap();
+        assert_eq!(gsa.fix_sats_prn, (1..=24).collect::<Vec<u32>>());
+        assert_eq!(gsa.pdop, Some(1.8));
+        assert_eq!(gsa.vdop, Some(1.4));
+        let gsa = parse_gsa_capped(&s, 3).unwrap();
+        assert_eq!(gsa.fix_sats_prn, vec![1, 2, 3]);
+        assert_eq!(gsa.hdop, Some(1.1));
+
+        let options = ParseOptions {
+            max_gsa_prns: 3,
+            ..ParseOptions::default()
+        };
+        let line = b"$GPGSA,A,3,23,31,22,16,03,07,,,,,,,1.8,1.1,1.4*3E";
+        match parse_with_options(line, &options) {
+            Ok(ParseResult::GSA(gsa)) => assert_eq!(gsa.fix_sats_prn, vec![23, 31, 22]),
+            result => panic!("GSA expected, got {:?}", result),
+        }
+        // duplicates and empty fields don't count
+        let line = b"$GPGSA,A,3,23,23,,31,22,,,,,,,,1.8,1.1,1.4*3C";
+        match parse_with_options(line, &options) {
+            Ok(ParseResult::GSA(gsa)) => assert_eq!(gsa.fix_sats_prn, vec![23, 31, 22]),
+            result => panic!("GSA expected, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn test_parse_gsa_no_fix_mode2() {
+        for line in &[
+            "$GNGSA,A,0,,,,,,,,,,,,,99.99,99.99,99.99*2F",
+            "$GNGSA,A,,,,,,,,,,,,,,99.99,99.99,99.99*1F",
+        ] {
+            let s = parse_nmea_sentence(line.as_bytes()).unwrap();
+            assert_eq!(s.checksum, s.calc_checksum());
+            let gsa = parse_gsa(&s).unwrap();
+            assert_eq!(gsa.mode2, GsaMode2::NoFix);
+            assert!(gsa.fix_sats_prn.is_empty());
+        }
+    }
+
     #[test]
     fn test_parse_vtg() {
-        let run_parse_vtg = |line: &str| -> Result<VtgData, String> {
+        let run_parse_vtg = |line: &str| -> Result<VtgData, NmeaError> {
             let s =
                 parse_nmea_sentence(line.as_bytes()).expect("VTG sentence initial parse failed");
             assert_eq!(s.checksum, s.calc_checksum());
@@ -1021,13 +4341,16 @@ mod tests {
         assert_eq!(
             VtgData {
                 true_course: None,
+                magnetic_course: None,
                 speed_over_ground: None,
             },
             run_parse_vtg("$GPVTG,,T,,M,,N,,K,N*2C").unwrap()
         );
         assert_eq!(
+            // 360 is wrapped to 0
             VtgData {
-                true_course: Some(360.),
+                true_course: Some(0.),
+                magnetic_course: Some(348.7),
                 speed_over_ground: Some(0.),
             },
             run_parse_vtg("$GPVTG,360.0,T,348.7,M,000.0,N,000.0,K*43").unwrap()
@@ -1035,9 +4358,497 @@ mod tests {
         assert_eq!(
             VtgData {
                 true_course: Some(54.7),
+                magnetic_course: Some(34.4),
                 speed_over_ground: Some(5.5),
             },
             run_parse_vtg("$GPVTG,054.7,T,034.4,M,005.5,N,010.2,K*48").unwrap()
         );
+        // speed in km/h only
+        let vtg = run_parse_vtg("$GPVTG,54.7,T,,M,,N,18.52,K*76").unwrap();
+        assert!(relative_eq!(vtg.speed_over_ground.unwrap(), 10.));
+        assert_eq!(vtg.magnetic_course, None);
+        // magnetic only display
+        let vtg = run_parse_vtg("$GPVTG,,T,034.4,M,005.5,N,010.2,K*60").unwrap();
+        assert_eq!(vtg.true_course, None);
+        assert_eq!(vtg.magnetic_course, Some(34.4));
+    }
+
+    #[test]
+    fn test_field_names() {
+        assert_eq!(
+            field_names(SentenceType::GGA),
+            &[
+                "UTC time",
+                "Latitude",
+                "N/S indicator",
+                "Longitude",
+                "E/W indicator",
+                "Fix quality",
+                "Number of satellites",
+                "HDOP",
+                "Altitude",
+                "Altitude units",
+                "Geoid height",
+                "Geoid height units",
+                "Age of DGPS data",
+                "DGPS station ID",
+            ]
+        );
+        let s = parse_nmea_sentence(
+            b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76",
+        )
+        .unwrap();
+        assert_eq!(
+            field_names(SentenceType::GGA).len(),
+            s.data.split(|c| *c == b',').count()
+        );
+        assert!(field_names(SentenceType::AAM).is_empty());
+    }
+
+    #[test]
+    fn test_parse_vdr() {
+        let run_parse_vdr = |line: &str| -> Result<VdrData, NmeaError> {
+            let s =
+                parse_nmea_sentence(line.as_bytes()).expect("VDR sentence initial parse failed");
+            assert_eq!(s.checksum, s.calc_checksum());
+            parse_vdr(&s)
+        };
+        assert_eq!(
+            VdrData {
+                direction_true: Some(10.1),
+                direction_magnetic: Some(12.3),
+                drift_knots: Some(1.2),
+            },
+            run_parse_vdr("$IIVDR,10.1,T,12.3,M,1.2,N*3A").unwrap()
+        );
+        assert_eq!(
+            VdrData {
+                direction_true: None,
+                direction_magnetic: None,
+                drift_knots: None,
+            },
+            run_parse_vdr("$IIVDR,,T,,M,,N*17").unwrap()
+        );
+    }
+
+    /// Sentence with valid frame and checksum around random payload
+    fn check_parse_no_panic(msg_id: u8, payload: Vec<u8>) -> bool {
+        const IDS: [&[u8]; 17] = [
+            b"GGA", b"GSV", b"RMC", b"GSA", b"VTG", b"GLL", b"VDR", b"HDM", b"ZTG", b"GNS", b"RMA",
+            b"MTW", b"MTA", b"TLL", b"GST", b"GGK", b"AAM",
+        ];
+        let mut line = b"$GP".to_vec();
+        line.extend_from_slice(IDS[usize::from(msg_id) % IDS.len()]);
+        line.push(b',');
+        line.extend(payload.iter().filter(|c| **c != b'*'));
+        let cs = checksum(line[1..].iter());
+        line.extend_from_slice(format!("*{:02X}", cs).as_bytes());
+        let lenient = ParseOptions {
+            lenient: true,
+            skip_invalid_fix: true,
+            decimal_comma: true,
+            max_gsa_prns: MAX_GSA_PRNS,
+        };
+        let _ = parse(&line);
+        let _ = parse_with_options(&line, &lenient);
+        let _ = parse(&payload);
+        true
+    }
+
+    #[test]
+    fn test_parse_no_panic() {
+        for &(msg_id, payload) in &[
+            (0, &b"\xff\xfe0000.0,\xc3\x28,N,,,,,,,"[..]),
+            (1, &b"65535,65535,65535,"[..]),
+            (2, &b"999999.99,A,99\xff9.0,N,,,,,999999,"[..]),
+            (
+                3,
+                &b"A,9,,,,,,,,,,,,,,,,,,,,,,,,,,,,,,,,,1e40,nan,-inf,7"[..],
+            ),
+            (9, &b",,,,,,,,,,,,,,,,,,"[..]),
+        ] {
+            assert!(check_parse_no_panic(msg_id, payload.to_vec()));
+        }
+        quickcheck::QuickCheck::new()
+            .tests(100_000)
+            .quickcheck(check_parse_no_panic as fn(u8, Vec<u8>) -> bool);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse(b"$HCHDM,238.5,M*26").err(),
+            Some(NmeaError::ChecksumMismatch {
+                expected: 0x25,
+                found: 0x26
+            })
+        );
+        assert_eq!(
+            parse(b"$GPHDM,238.5").err(),
+            Some(NmeaError::ParsingError(nom::Err::Error(
+                nom::error::ErrorKind::TakeUntil
+            )))
+        );
+        assert_eq!(
+            parse(b"$HC\xff\xff\xff,1*E9").err(),
+            Some(NmeaError::UnknownSentenceType)
+        );
+        assert!(matches!(
+            parse(b"$HCXXX,238.5,M*3C"),
+            Ok(ParseResult::Unsupported {
+                talker: TalkerId::HeadingCompass,
+                sentence: SentenceType::None
+            })
+        ));
+        assert!(matches!(
+            parse(b"$GPAAM,,,,,*76"),
+            Ok(ParseResult::Unsupported {
+                talker: TalkerId::Gps,
+                sentence: SentenceType::AAM
+            })
+        ));
+        // unknown talker of unsupported sentence
+        assert!(matches!(
+            parse(b"$XQAAM,,,,,*68"),
+            Ok(ParseResult::Unsupported {
+                talker: TalkerId::Other([b'X', b'Q']),
+                sentence: SentenceType::AAM
+            })
+        ));
+        let s = parse_nmea_sentence(b"$HCHDM,238.5,M*25").unwrap();
+        let err = parse_vdr(&s).unwrap_err();
+        assert_eq!(
+            err,
+            NmeaError::UnexpectedSentence {
+                expected: SentenceType::VDR,
+                got: *b"HDM"
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "VDR message should starts with $..VDR, got $..HDM"
+        );
+        let err = parse(b"$GPHDM,238.5").err().unwrap();
+        assert_eq!(err.to_string(), "Take until");
+        #[cfg(feature = "std")]
+        {
+            assert!(std::error::Error::source(&err).is_some());
+            assert!(std::error::Error::source(&NmeaError::TooLong).is_none());
+            let boxed = || -> Result<(), Box<dyn std::error::Error>> {
+                parse(b"$HCHDM,238.5,M*26")?;
+                Ok(())
+            };
+            assert_eq!(
+                boxed().unwrap_err().to_string(),
+                "Checksum mismatch: calculated 25, sentence has 26"
+            );
+        }
+        assert_eq!(
+            parse_nmea_sentence(&[b'$'; 103]).err(),
+            Some(NmeaError::TooLong)
+        );
+    }
+
+    #[test]
+    fn test_parse_hdm() {
+        let run_parse_hdm = |line: &str| -> Result<HdmData, NmeaError> {
+            let s =
+                parse_nmea_sentence(line.as_bytes()).expect("HDM sentence initial parse failed");
+            assert_eq!(s.checksum, s.calc_checksum());
+            parse_hdm(&s)
+        };
+        assert_eq!(
+            HdmData {
+                heading: Some(238.5),
+            },
+            run_parse_hdm("$HCHDM,238.5,M*25").unwrap()
+        );
+        assert_eq!(
+            HdmData { heading: None },
+            run_parse_hdm("$HCHDM,,M*07").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_tll() {
+        let run_parse_tll = |line: &[u8]| -> Result<TllData, NmeaError> {
+            let s = parse_nmea_sentence(line).unwrap();
+            assert_eq!(s.checksum, s.calc_checksum());
+            parse_tll(&s)
+        };
+        let tll =
+            run_parse_tll(b"$RATLL,01,5321.6802,N,00630.3372,W,BUOY 1,092750.00,T,R*7B").unwrap();
+        assert_eq!(tll.target_number, 1);
+        assert!(relative_eq!(tll.latitude.unwrap(), 53. + 21.6802 / 60.));
+        assert!(relative_eq!(tll.longitude.unwrap(), -(6. + 30.3372 / 60.)));
+        assert_eq!(tll.name, "BUOY 1");
+        assert_eq!(tll.time, NaiveTime::from_hms_opt(9, 27, 50));
+        assert_eq!(tll.status, 'T');
+        assert_eq!(tll.reference_target, Some('R'));
+
+        assert_eq!(
+            run_parse_tll(b"$RATLL,15,,,,,,,L,*23").unwrap(),
+            TllData {
+                target_number: 15,
+                latitude: None,
+                longitude: None,
+                name: String::new(),
+                time: None,
+                status: 'L',
+                reference_target: None,
+            }
+        );
+        assert!(run_parse_tll(b"$RATLL,15,,,,,,,X,*37").is_err());
+        assert!(matches!(
+            parse(b"$RATLL,15,,,,,,,L,*23"),
+            Ok(ParseResult::TLL(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_gst() {
+        let gst = match parse(b"$GPGST,172814.0,0.006,0.023,0.020,273.6,0.023,0.020,0.031*6A") {
+            Ok(ParseResult::GST(gst)) => gst,
+            result => panic!("GST expected, got {:?}", result),
+        };
+        assert_eq!(gst.fix_time, NaiveTime::from_hms_opt(17, 28, 14));
+        assert_eq!(gst.rms_sd, Some(0.006));
+        assert_eq!(gst.ellipse_orientation, Some(273.6));
+        assert_eq!(gst.lat_accuracy(1.), Some(0.023));
+        assert!(relative_eq!(gst.lon_accuracy(2.).unwrap(), 0.040));
+        assert!(relative_eq!(gst.alt_accuracy(2.).unwrap(), 0.062));
+        assert!(relative_eq!(gst.alt_accuracy(3.).unwrap(), 0.093));
+
+        let gst = match parse(b"$GPGST,172814.0,,,,,,,*40") {
+            Ok(ParseResult::GST(gst)) => gst,
+            result => panic!("GST expected, got {:?}", result),
+        };
+        assert_eq!(
+            gst,
+            GstData {
+                fix_time: NaiveTime::from_hms_opt(17, 28, 14),
+                ..GstData::default()
+            }
+        );
+        assert_eq!(gst.lat_accuracy(1.), None);
+        assert_eq!(gst.lon_accuracy(1.), None);
+        assert_eq!(gst.alt_accuracy(1.), None);
+    }
+
+    #[test]
+    fn test_parse_ggk() {
+        let ggk = match parse(
+            b"$GPGGK,102939.00,051910,5000.97323841,N,00827.62010742,E,3,09,1.9,EHT150.790,M*48",
+        ) {
+            Ok(ParseResult::GGK(ggk)) => ggk,
+            result => panic!("GGK expected, got {:?}", result),
+        };
+        assert_eq!(ggk.fix_time, NaiveTime::from_hms_opt(10, 29, 39));
+        assert_eq!(ggk.fix_date, NaiveDate::from_ymd_opt(2010, 5, 19));
+        assert!(relative_eq!(ggk.latitude.unwrap(), 50. + 0.97323841 / 60.));
+        assert!(relative_eq!(ggk.longitude.unwrap(), 8. + 27.62010742 / 60.));
+        assert_eq!(ggk.quality, Some(GgkQuality::RtkFixed));
+        assert_eq!(ggk.fix_satellites, Some(9));
+        assert_eq!(ggk.dop, Some(1.9));
+        assert_eq!(ggk.ellipsoidal_height, Some(150.790));
+
+        let ggk = match parse(b"$GPGGK,102939.00,,,,,,0,,,,*6E") {
+            Ok(ParseResult::GGK(ggk)) => ggk,
+            result => panic!("GGK expected, got {:?}", result),
+        };
+        assert_eq!(
+            ggk,
+            GgkData {
+                fix_time: NaiveTime::from_hms_opt(10, 29, 39),
+                quality: Some(GgkQuality::Invalid),
+                ..GgkData::default()
+            }
+        );
+
+        assert_eq!(
+            parse(
+                b"$GPGGK,102939.00,051910,9500.97323841,N,00827.62010742,E,3,09,1.9,EHT150.790,M*41"
+            )
+            .err(),
+            Some(NmeaError::InvalidLatitude)
+        );
+    }
+
+    #[test]
+    fn test_ggk_quality() {
+        let expected = [
+            (0, GgkQuality::Invalid),
+            (1, GgkQuality::Autonomous),
+            (2, GgkQuality::RtkFloat),
+            (3, GgkQuality::RtkFixed),
+            (4, GgkQuality::DGps),
+            (5, GgkQuality::Sbas),
+            (6, GgkQuality::NetworkRtkFloat),
+            (7, GgkQuality::Other(7)),
+            (255, GgkQuality::Other(255)),
+        ];
+        for (value, quality) in expected.iter() {
+            assert_eq!(GgkQuality::from(*value), *quality);
+        }
+    }
+
+    #[test]
+    fn test_parse_mtw_mta() {
+        let mtw = match parse(b"$YXMTW,17.5,C*11").unwrap() {
+            ParseResult::MTW(mtw) => mtw,
+            _ => panic!("expected MTW"),
+        };
+        assert_eq!(
+            MtwData {
+                temperature: Some(17.5),
+                unit: Some(TemperatureUnit::Celsius),
+            },
+            mtw
+        );
+        assert_eq!(mtw.temperature_celsius(), Some(17.5));
+
+        let mta = match parse(b"$WIMTA,63.5,F*1E").unwrap() {
+            ParseResult::MTA(mta) => mta,
+            _ => panic!("expected MTA"),
+        };
+        assert_eq!(mta.unit, Some(TemperatureUnit::Fahrenheit));
+        assert!(relative_eq!(mta.temperature_celsius().unwrap(), 17.5));
+
+        let s = parse_nmea_sentence(b"$WIMTA,,C*05").unwrap();
+        assert_eq!(parse_mta(&s).unwrap().temperature_celsius(), None);
+        assert!(parse_mtw(&s).is_err());
+    }
+
+    #[test]
+    fn test_parse_ztg() {
+        let run_parse_ztg = |line: &str| -> Result<ZtgData, NmeaError> {
+            let s =
+                parse_nmea_sentence(line.as_bytes()).expect("ZTG sentence initial parse failed");
+            assert_eq!(s.checksum, s.calc_checksum());
+            parse_ztg(&s)
+        };
+        assert_eq!(
+            ZtgData {
+                utc: NaiveTime::from_hms_milli_opt(14, 58, 32, 120),
+                time_to_go: NaiveTime::from_hms_milli_opt(4, 23, 59, 170),
+                waypoint_id: "WPT".to_string(),
+            },
+            run_parse_ztg("$GPZTG,145832.12,042359.17,WPT*24").unwrap()
+        );
+        assert_eq!(
+            ZtgData {
+                utc: None,
+                time_to_go: None,
+                waypoint_id: String::new(),
+            },
+            run_parse_ztg("$GPZTG,,,*72").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_rma() {
+        let run_parse_rma = |line: &str| -> Result<RmaData, NmeaError> {
+            let s =
+                parse_nmea_sentence(line.as_bytes()).expect("RMA sentence initial parse failed");
+            assert_eq!(s.checksum, s.calc_checksum());
+            parse_rma(&s)
+        };
+
+        let rma = run_parse_rma("$GPRMA,A,4916.45,N,12311.12,W,12345.6,23456.7,5.5,54.7,20.3,E*6F")
+            .unwrap();
+        assert!(rma.data_valid);
+        assert!(relative_eq!(rma.latitude.unwrap(), 49. + 16.45 / 60.));
+        assert!(relative_eq!(rma.longitude.unwrap(), -(123. + 11.12 / 60.)));
+        assert_eq!(rma.time_difference_a, Some(12345.6));
+        assert_eq!(rma.time_difference_b, Some(23456.7));
+        assert_eq!(rma.speed_over_ground, Some(5.5));
+        assert_eq!(rma.true_course, Some(54.7));
+        assert_eq!(rma.magnetic_variation, Some(20.3));
+
+        // with mode indicator
+        let rma =
+            run_parse_rma("$LCRMA,A,4916.45,N,12311.12,W,12345.6,,0.5,354.7,20.3,W,A*11").unwrap();
+        assert_eq!(rma.time_difference_b, None);
+        assert_eq!(rma.magnetic_variation, Some(-20.3));
+
+        assert_eq!(
+            run_parse_rma("$GPRMA,V,,,,,,,,,,*33").unwrap(),
+            RmaData {
+                data_valid: false,
+                latitude: None,
+                longitude: None,
+                time_difference_a: None,
+                time_difference_b: None,
+                speed_over_ground: None,
+                true_course: None,
+                magnetic_variation: None,
+            }
+        );
+        assert!(matches!(
+            parse(b"$GPRMA,V,,,,,,,,,,*33"),
+            Ok(ParseResult::RMA(_))
+        ));
+        assert!(run_parse_rma("$GPRMA,X,,,,,,,,,,*3D").is_err());
+    }
+
+    #[test]
+    fn test_parse_gns() {
+        let run_parse_gns = |line: &str| -> Result<GnsData, NmeaError> {
+            let s =
+                parse_nmea_sentence(line.as_bytes()).expect("GNS sentence initial parse failed");
+            assert_eq!(s.checksum, s.calc_checksum());
+            parse_gns(&s)
+        };
+
+        let gns = run_parse_gns(
+            "$GNGNS,112257.00,3844.24011,N,00908.43828,W,DDN,10,1.0,54.3,51.9,2.0,0030,S*60",
+        )
+        .unwrap();
+        assert_eq!(gns.fix_time, NaiveTime::from_hms_opt(11, 22, 57));
+        assert!(relative_eq!(gns.latitude.unwrap(), 38. + 44.24011 / 60.));
+        assert!(relative_eq!(gns.longitude.unwrap(), -(9. + 8.43828 / 60.)));
+        assert_eq!(gns.mode_indicator, "DDN");
+        assert_eq!(gns.fix_satellites, Some(10));
+        assert_eq!(gns.hdop, Some(1.0));
+        assert_eq!(gns.altitude, Some(54.3));
+        assert_eq!(gns.geoid_height, Some(51.9));
+        assert_eq!(gns.age_of_differential, Some(2.0));
+        assert_eq!(gns.differential_station_id, Some(30));
+        assert_eq!(gns.nav_status, Some('S'));
+
+        let gns =
+            run_parse_gns("$GNGNS,014035.00,4332.69262,S,17235.48549,E,RR,13,0.9,25.63,11.24,,*70")
+                .unwrap();
+        assert_eq!(gns.mode_indicator, "RR");
+        assert_eq!(gns.geoid_height, Some(11.24));
+        assert_eq!(gns.age_of_differential, None);
+        assert_eq!(gns.differential_station_id, None);
+        assert_eq!(gns.nav_status, None);
+
+        // short form, without DGPS fields
+        let gns =
+            run_parse_gns("$GPGNS,014035.00,4332.69262,S,17235.48549,E,AA,13,0.9,25.63,11.24*6E")
+                .unwrap();
+        assert_eq!(gns.altitude, Some(25.63));
+        assert_eq!(gns.geoid_height, Some(11.24));
+        assert_eq!(gns.age_of_differential, None);
+
+        let gns = run_parse_gns("$GNGNS,,,,,,NNN,,,,,,,V*67").unwrap();
+        assert_eq!(
+            GnsData {
+                fix_time: None,
+                latitude: None,
+                longitude: None,
+                mode_indicator: "NNN".to_string(),
+                fix_satellites: None,
+                hdop: None,
+                altitude: None,
+                geoid_height: None,
+                age_of_differential: None,
+                differential_station_id: None,
+                nav_status: Some('V'),
+            },
+            gns
+        );
     }
 }
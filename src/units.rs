@@ -0,0 +1,98 @@
+//! Accessors returning values as `uom` quantities, so units are checked at compile time
+
+use uom::si::f32::{Length, Velocity};
+use uom::si::length::meter;
+use uom::si::velocity::knot;
+
+use crate::{GgaData, GnsData, RmaData, RmcData, VtgData};
+
+fn knots(v: Option<f32>) -> Option<Velocity> {
+    v.map(Velocity::new::<knot>)
+}
+
+fn meters(v: Option<f32>) -> Option<Length> {
+    v.map(Length::new::<meter>)
+}
+
+impl VtgData {
+    /// Speed over ground, whether it was sent in knots or km/h
+    pub fn speed(&self) -> Option<Velocity> {
+        knots(self.speed_over_ground)
+    }
+}
+
+impl RmcData {
+    /// Speed over ground
+    pub fn speed(&self) -> Option<Velocity> {
+        knots(self.speed_over_ground)
+    }
+}
+
+impl RmaData {
+    /// Speed over ground
+    pub fn speed(&self) -> Option<Velocity> {
+        knots(self.speed_over_ground)
+    }
+}
+
+impl GgaData {
+    /// Altitude above mean sea level
+    pub fn altitude_length(&self) -> Option<Length> {
+        meters(self.altitude)
+    }
+
+    /// Height of geoid above WGS84 ellipsoid
+    pub fn geoid_height_length(&self) -> Option<Length> {
+        meters(self.geoid_height)
+    }
+}
+
+impl GnsData {
+    /// Altitude above mean sea level
+    pub fn altitude_length(&self) -> Option<Length> {
+        meters(self.altitude)
+    }
+
+    /// Height of geoid above WGS84 ellipsoid
+    pub fn geoid_height_length(&self) -> Option<Length> {
+        meters(self.geoid_height)
+    }
+}
+
+#[cfg(all(test, feature = "all-sentences"))]
+mod tests {
+    use super::*;
+    use crate::{parse, ParseResult};
+    use uom::si::length::foot;
+    use uom::si::velocity::{kilometer_per_hour, meter_per_second};
+
+    #[test]
+    fn test_uom_speed() {
+        let vtg = match parse(b"$GPVTG,54.7,T,,M,5.5,N,10.2,K*65") {
+            Ok(ParseResult::VTG(vtg)) => vtg,
+            _ => panic!("VTG expected"),
+        };
+        let speed = vtg.speed().unwrap();
+        assert!((speed.get::<meter_per_second>() - 5.5 * 1852. / 3600.).abs() < 1e-5);
+        assert!((speed.get::<kilometer_per_hour>() - 10.186).abs() < 1e-4);
+
+        // km/h only
+        let vtg = match parse(b"$GPVTG,54.7,T,,M,,N,18.52,K*76") {
+            Ok(ParseResult::VTG(vtg)) => vtg,
+            _ => panic!("VTG expected"),
+        };
+        assert!((vtg.speed().unwrap().get::<kilometer_per_hour>() - 18.52).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_uom_altitude() {
+        let gga = match parse(
+            b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,55.2,M,,*76",
+        ) {
+            Ok(ParseResult::GGA(gga)) => gga,
+            _ => panic!("GGA expected"),
+        };
+        assert!((gga.altitude_length().unwrap().get::<foot>() - 202.427_17).abs() < 1e-3);
+        assert_eq!(gga.geoid_height_length().unwrap().get::<meter>(), 55.2);
+    }
+}
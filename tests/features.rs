@@ -0,0 +1,23 @@
+use nmea::{parse, ParseResult};
+
+const GSV: &[u8] = b"$GPGSV,3,1,11,10,63,137,17,07,61,098,15,05,59,290,20,08,54,157,30*70";
+
+#[cfg(feature = "gsv")]
+#[test]
+fn test_gsv_enabled() {
+    assert!(matches!(parse(GSV), Ok(ParseResult::GSV(_))));
+}
+
+#[cfg(not(feature = "gsv"))]
+#[test]
+fn test_gsv_excluded() {
+    use nmea::{SentenceType, TalkerId};
+
+    assert!(matches!(
+        parse(GSV),
+        Ok(ParseResult::Unsupported {
+            talker: TalkerId::Gps,
+            sentence: SentenceType::GSV
+        })
+    ));
+}
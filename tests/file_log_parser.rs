@@ -1,3 +1,5 @@
+#![cfg(all(feature = "std", feature = "all-sentences"))]
+
 use std::{
     error::Error,
     fs::File,
@@ -6,7 +8,7 @@ use std::{
 };
 
 fn err_to_string<E: Error>(e: E) -> String {
-    e.description().to_string()
+    e.to_string()
 }
 
 fn process_file(n: &Path) -> Result<Vec<String>, String> {
@@ -31,7 +33,7 @@ fn test_parse_file_log() {
         .unwrap_or_else(|err| panic!("process file failed with error '{}'", err));
 
     let expected: Vec<_> =
-        BufReader::new(File::open(&Path::new("tests").join("nmea1.log.expected")).unwrap())
+        BufReader::new(File::open(Path::new("tests").join("nmea1.log.expected")).unwrap())
             .lines()
             .map(|v| v.unwrap())
             .collect();
@@ -40,7 +42,7 @@ fn test_parse_file_log() {
 
 #[test]
 fn test_parse_issue_2() {
-    let mut input = BufReader::new(File::open(&Path::new("tests").join("nmea2.log")).unwrap());
+    let mut input = BufReader::new(File::open(Path::new("tests").join("nmea2.log")).unwrap());
     let mut nmea = nmea::Nmea::new();
     for _ in 0..100 {
         let mut buffer = String::new();